@@ -8,6 +8,8 @@
 import * as web3 from '@solana/web3.js';
 import * as beet from '@metaplex-foundation/beet';
 import * as beetSolana from '@metaplex-foundation/beet-solana';
+import { AuthorityEntry, authorityEntryBeet } from '../types/AuthorityEntry';
+import { Encoding, encodingBeet } from '../types/Encoding';
 
 /**
  * Arguments used to create {@link JsonMetadata}
@@ -16,8 +18,16 @@ import * as beetSolana from '@metaplex-foundation/beet-solana';
  */
 export type JsonMetadataArgs = {
   bump: number;
+  version: number;
   mutable: boolean;
-  authorities: web3.PublicKey[];
+  authorities: AuthorityEntry[];
+  threshold: number;
+  schema: beet.COption<web3.PublicKey>;
+  pendingAuthorities: AuthorityEntry[];
+  revision: beet.bignum;
+  lastUpdatedSlot: beet.bignum;
+  hash: number[] /* size: 32 */;
+  encoding: Encoding;
 };
 /**
  * Holds the data for the {@link JsonMetadata} Account and provides de/serialization
@@ -29,15 +39,35 @@ export type JsonMetadataArgs = {
 export class JsonMetadata implements JsonMetadataArgs {
   private constructor(
     readonly bump: number,
+    readonly version: number,
     readonly mutable: boolean,
-    readonly authorities: web3.PublicKey[],
+    readonly authorities: AuthorityEntry[],
+    readonly threshold: number,
+    readonly schema: beet.COption<web3.PublicKey>,
+    readonly pendingAuthorities: AuthorityEntry[],
+    readonly revision: beet.bignum,
+    readonly lastUpdatedSlot: beet.bignum,
+    readonly hash: number[] /* size: 32 */,
+    readonly encoding: Encoding,
   ) {}
 
   /**
    * Creates a {@link JsonMetadata} instance from the provided args.
    */
   static fromArgs(args: JsonMetadataArgs) {
-    return new JsonMetadata(args.bump, args.mutable, args.authorities);
+    return new JsonMetadata(
+      args.bump,
+      args.version,
+      args.mutable,
+      args.authorities,
+      args.threshold,
+      args.schema,
+      args.pendingAuthorities,
+      args.revision,
+      args.lastUpdatedSlot,
+      args.hash,
+      args.encoding,
+    );
   }
 
   /**
@@ -132,8 +162,36 @@ export class JsonMetadata implements JsonMetadataArgs {
   pretty() {
     return {
       bump: this.bump,
+      version: this.version,
       mutable: this.mutable,
       authorities: this.authorities,
+      threshold: this.threshold,
+      schema: this.schema,
+      pendingAuthorities: this.pendingAuthorities,
+      revision: (() => {
+        const x = <{ toNumber: () => number }>this.revision;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      lastUpdatedSlot: (() => {
+        const x = <{ toNumber: () => number }>this.lastUpdatedSlot;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      hash: this.hash,
+      encoding: 'Encoding.' + Encoding[this.encoding],
     };
   }
 }
@@ -145,8 +203,16 @@ export class JsonMetadata implements JsonMetadataArgs {
 export const jsonMetadataBeet = new beet.FixableBeetStruct<JsonMetadata, JsonMetadataArgs>(
   [
     ['bump', beet.u8],
+    ['version', beet.u8],
     ['mutable', beet.bool],
-    ['authorities', beet.array(beetSolana.publicKey)],
+    ['authorities', beet.array(authorityEntryBeet)],
+    ['threshold', beet.u8],
+    ['schema', beet.coption(beetSolana.publicKey)],
+    ['pendingAuthorities', beet.array(authorityEntryBeet)],
+    ['revision', beet.u64],
+    ['lastUpdatedSlot', beet.u64],
+    ['hash', beet.uniformFixedSizeArray(beet.u8, 32)],
+    ['encoding', encodingBeet],
   ],
   JsonMetadata.fromArgs,
   'JsonMetadata',
@@ -1,5 +1,7 @@
+export * from './Attestation';
 export * from './JsonMetadata';
 
+import { Attestation } from './Attestation';
 import { JsonMetadata } from './JsonMetadata';
 
-export const accountProviders = { JsonMetadata };
+export const accountProviders = { Attestation, JsonMetadata };
@@ -154,6 +154,572 @@ export class InvalidAuthorityError extends Error {
 createErrorFromCodeLookup.set(0x6, () => new InvalidAuthorityError());
 createErrorFromNameLookup.set('InvalidAuthority', () => new InvalidAuthorityError());
 
+/**
+ * PatchFailed: 'The JSON patch could not be applied.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PatchFailedError extends Error {
+  readonly code: number = 0x7;
+  readonly name: string = 'PatchFailed';
+  constructor() {
+    super('The JSON patch could not be applied.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PatchFailedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x7, () => new PatchFailedError());
+createErrorFromNameLookup.set('PatchFailed', () => new PatchFailedError());
+
+/**
+ * InvalidPointer: 'The JSON pointer is invalid.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidPointerError extends Error {
+  readonly code: number = 0x8;
+  readonly name: string = 'InvalidPointer';
+  constructor() {
+    super('The JSON pointer is invalid.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidPointerError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x8, () => new InvalidPointerError());
+createErrorFromNameLookup.set('InvalidPointer', () => new InvalidPointerError());
+
+/**
+ * PointerNotFound: 'The JSON pointer does not reference an existing location.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PointerNotFoundError extends Error {
+  readonly code: number = 0x9;
+  readonly name: string = 'PointerNotFound';
+  constructor() {
+    super('The JSON pointer does not reference an existing location.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PointerNotFoundError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x9, () => new PointerNotFoundError());
+createErrorFromNameLookup.set('PointerNotFound', () => new PointerNotFoundError());
+
+/**
+ * Immutable: 'The JSON account is immutable.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ImmutableError extends Error {
+  readonly code: number = 0xa;
+  readonly name: string = 'Immutable';
+  constructor() {
+    super('The JSON account is immutable.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ImmutableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0xa, () => new ImmutableError());
+createErrorFromNameLookup.set('Immutable', () => new ImmutableError());
+
+/**
+ * BufferDerivedKeyInvalid: 'The key for the upload buffer account is invalid.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BufferDerivedKeyInvalidError extends Error {
+  readonly code: number = 0xb;
+  readonly name: string = 'BufferDerivedKeyInvalid';
+  constructor() {
+    super('The key for the upload buffer account is invalid.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BufferDerivedKeyInvalidError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0xb, () => new BufferDerivedKeyInvalidError());
+createErrorFromNameLookup.set('BufferDerivedKeyInvalid', () => new BufferDerivedKeyInvalidError());
+
+/**
+ * ChunkOutOfBounds: 'The chunk does not fit in the upload buffer.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChunkOutOfBoundsError extends Error {
+  readonly code: number = 0xc;
+  readonly name: string = 'ChunkOutOfBounds';
+  constructor() {
+    super('The chunk does not fit in the upload buffer.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChunkOutOfBoundsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0xc, () => new ChunkOutOfBoundsError());
+createErrorFromNameLookup.set('ChunkOutOfBounds', () => new ChunkOutOfBoundsError());
+
+/**
+ * SchemaDerivedKeyInvalid: 'The key for the schema account is invalid.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SchemaDerivedKeyInvalidError extends Error {
+  readonly code: number = 0xd;
+  readonly name: string = 'SchemaDerivedKeyInvalid';
+  constructor() {
+    super('The key for the schema account is invalid.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SchemaDerivedKeyInvalidError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0xd, () => new SchemaDerivedKeyInvalidError());
+createErrorFromNameLookup.set('SchemaDerivedKeyInvalid', () => new SchemaDerivedKeyInvalidError());
+
+/**
+ * InvalidSchema: 'The schema is invalid or uses unsupported keywords.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidSchemaError extends Error {
+  readonly code: number = 0xe;
+  readonly name: string = 'InvalidSchema';
+  constructor() {
+    super('The schema is invalid or uses unsupported keywords.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidSchemaError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0xe, () => new InvalidSchemaError());
+createErrorFromNameLookup.set('InvalidSchema', () => new InvalidSchemaError());
+
+/**
+ * SchemaViolation: 'The JSON data does not conform to the attached schema.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SchemaViolationError extends Error {
+  readonly code: number = 0xf;
+  readonly name: string = 'SchemaViolation';
+  constructor() {
+    super('The JSON data does not conform to the attached schema.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SchemaViolationError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0xf, () => new SchemaViolationError());
+createErrorFromNameLookup.set('SchemaViolation', () => new SchemaViolationError());
+
+/**
+ * JsonDerivedKeyInvalid: 'The key for the JSON account is invalid.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class JsonDerivedKeyInvalidError extends Error {
+  readonly code: number = 0x10;
+  readonly name: string = 'JsonDerivedKeyInvalid';
+  constructor() {
+    super('The key for the JSON account is invalid.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, JsonDerivedKeyInvalidError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x10, () => new JsonDerivedKeyInvalidError());
+createErrorFromNameLookup.set('JsonDerivedKeyInvalid', () => new JsonDerivedKeyInvalidError());
+
+/**
+ * MetadataNeedsMigration: 'The JSON metadata account uses a legacy layout and must be migrated first.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MetadataNeedsMigrationError extends Error {
+  readonly code: number = 0x11;
+  readonly name: string = 'MetadataNeedsMigration';
+  constructor() {
+    super('The JSON metadata account uses a legacy layout and must be migrated first.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MetadataNeedsMigrationError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11, () => new MetadataNeedsMigrationError());
+createErrorFromNameLookup.set('MetadataNeedsMigration', () => new MetadataNeedsMigrationError());
+
+/**
+ * InvalidRoles: 'The roles are empty or contain unknown bits.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRolesError extends Error {
+  readonly code: number = 0x12;
+  readonly name: string = 'InvalidRoles';
+  constructor() {
+    super('The roles are empty or contain unknown bits.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRolesError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x12, () => new InvalidRolesError());
+createErrorFromNameLookup.set('InvalidRoles', () => new InvalidRolesError());
+
+/**
+ * PathNotPermitted: 'The authority is not permitted to change this path.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PathNotPermittedError extends Error {
+  readonly code: number = 0x13;
+  readonly name: string = 'PathNotPermitted';
+  constructor() {
+    super('The authority is not permitted to change this path.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PathNotPermittedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x13, () => new PathNotPermittedError());
+createErrorFromNameLookup.set('PathNotPermitted', () => new PathNotPermittedError());
+
+/**
+ * LastAuthority: 'The last admin authority can only be given up with RenounceAll.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class LastAuthorityError extends Error {
+  readonly code: number = 0x14;
+  readonly name: string = 'LastAuthority';
+  constructor() {
+    super('The last admin authority can only be given up with RenounceAll.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, LastAuthorityError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x14, () => new LastAuthorityError());
+createErrorFromNameLookup.set('LastAuthority', () => new LastAuthorityError());
+
+/**
+ * ProposalNotFound: 'There is no pending proposal for this authority.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ProposalNotFoundError extends Error {
+  readonly code: number = 0x15;
+  readonly name: string = 'ProposalNotFound';
+  constructor() {
+    super('There is no pending proposal for this authority.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ProposalNotFoundError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x15, () => new ProposalNotFoundError());
+createErrorFromNameLookup.set('ProposalNotFound', () => new ProposalNotFoundError());
+
+/**
+ * NotEnoughSigners: 'Fewer authorities signed than the threshold requires.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotEnoughSignersError extends Error {
+  readonly code: number = 0x16;
+  readonly name: string = 'NotEnoughSigners';
+  constructor() {
+    super('Fewer authorities signed than the threshold requires.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotEnoughSignersError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x16, () => new NotEnoughSignersError());
+createErrorFromNameLookup.set('NotEnoughSigners', () => new NotEnoughSignersError());
+
+/**
+ * InvalidThreshold: 'The threshold must be at least one and reachable by the admins and writers.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidThresholdError extends Error {
+  readonly code: number = 0x17;
+  readonly name: string = 'InvalidThreshold';
+  constructor() {
+    super('The threshold must be at least one and reachable by the admins and writers.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidThresholdError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17, () => new InvalidThresholdError());
+createErrorFromNameLookup.set('InvalidThreshold', () => new InvalidThresholdError());
+
+/**
+ * RevisionMismatch: 'The JSON data has changed since the expected revision.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RevisionMismatchError extends Error {
+  readonly code: number = 0x18;
+  readonly name: string = 'RevisionMismatch';
+  constructor() {
+    super('The JSON data has changed since the expected revision.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RevisionMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x18, () => new RevisionMismatchError());
+createErrorFromNameLookup.set('RevisionMismatch', () => new RevisionMismatchError());
+
+/**
+ * HashMismatch: 'The JSON data does not match the expected hash.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class HashMismatchError extends Error {
+  readonly code: number = 0x19;
+  readonly name: string = 'HashMismatch';
+  constructor() {
+    super('The JSON data does not match the expected hash.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, HashMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x19, () => new HashMismatchError());
+createErrorFromNameLookup.set('HashMismatch', () => new HashMismatchError());
+
+/**
+ * InvalidSignatureInstruction: 'The previous instruction must verify a single ed25519 signature over its own data.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidSignatureInstructionError extends Error {
+  readonly code: number = 0x1a;
+  readonly name: string = 'InvalidSignatureInstruction';
+  constructor() {
+    super('The previous instruction must verify a single ed25519 signature over its own data.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidSignatureInstructionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1a, () => new InvalidSignatureInstructionError());
+createErrorFromNameLookup.set(
+  'InvalidSignatureInstruction',
+  () => new InvalidSignatureInstructionError(),
+);
+
+/**
+ * AttestationMismatch: 'The signed message does not match the current JSON data.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AttestationMismatchError extends Error {
+  readonly code: number = 0x1b;
+  readonly name: string = 'AttestationMismatch';
+  constructor() {
+    super('The signed message does not match the current JSON data.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AttestationMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1b, () => new AttestationMismatchError());
+createErrorFromNameLookup.set('AttestationMismatch', () => new AttestationMismatchError());
+
+/**
+ * AttestationDerivedKeyInvalid: 'The attestation account does not match the derived address.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AttestationDerivedKeyInvalidError extends Error {
+  readonly code: number = 0x1c;
+  readonly name: string = 'AttestationDerivedKeyInvalid';
+  constructor() {
+    super('The attestation account does not match the derived address.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AttestationDerivedKeyInvalidError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1c, () => new AttestationDerivedKeyInvalidError());
+createErrorFromNameLookup.set(
+  'AttestationDerivedKeyInvalid',
+  () => new AttestationDerivedKeyInvalidError(),
+);
+
+/**
+ * TypeMismatch: 'The value at the JSON Pointer does not have the requested type.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TypeMismatchError extends Error {
+  readonly code: number = 0x1d;
+  readonly name: string = 'TypeMismatch';
+  constructor() {
+    super('The value at the JSON Pointer does not have the requested type.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TypeMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1d, () => new TypeMismatchError());
+createErrorFromNameLookup.set('TypeMismatch', () => new TypeMismatchError());
+
+/**
+ * InvalidUploader: 'Only the authority that began the upload may write, commit or cancel it.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidUploaderError extends Error {
+  readonly code: number = 0x1e;
+  readonly name: string = 'InvalidUploader';
+  constructor() {
+    super('Only the authority that began the upload may write, commit or cancel it.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidUploaderError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1e, () => new InvalidUploaderError());
+createErrorFromNameLookup.set('InvalidUploader', () => new InvalidUploaderError());
+
+/**
+ * InvalidSeed: 'The seed must be between 1 and 32 bytes long.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidSeedError extends Error {
+  readonly code: number = 0x1f;
+  readonly name: string = 'InvalidSeed';
+  constructor() {
+    super('The seed must be between 1 and 32 bytes long.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidSeedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1f, () => new InvalidSeedError());
+createErrorFromNameLookup.set('InvalidSeed', () => new InvalidSeedError());
+
+/**
+ * InvalidMint: 'The mint account does not match the arguments or is not owned by a token program.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMintError extends Error {
+  readonly code: number = 0x20;
+  readonly name: string = 'InvalidMint';
+  constructor() {
+    super('The mint account does not match the arguments or is not owned by a token program.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x20, () => new InvalidMintError());
+createErrorFromNameLookup.set('InvalidMint', () => new InvalidMintError());
+
+/**
+ * AdminRequiresProposal: 'Admins can only be added by proposing them and having them accept.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AdminRequiresProposalError extends Error {
+  readonly code: number = 0x21;
+  readonly name: string = 'AdminRequiresProposal';
+  constructor() {
+    super('Admins can only be added by proposing them and having them accept.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AdminRequiresProposalError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x21, () => new AdminRequiresProposalError());
+createErrorFromNameLookup.set('AdminRequiresProposal', () => new AdminRequiresProposalError());
+
+/**
+ * UnsupportedEncoding: 'The JSON data is not stored as JSON text, so it can't be read in place.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UnsupportedEncodingError extends Error {
+  readonly code: number = 0x22;
+  readonly name: string = 'UnsupportedEncoding';
+  constructor() {
+    super("The JSON data is not stored as JSON text, so it can't be read in place.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UnsupportedEncodingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x22, () => new UnsupportedEncodingError());
+createErrorFromNameLookup.set('UnsupportedEncoding', () => new UnsupportedEncodingError());
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
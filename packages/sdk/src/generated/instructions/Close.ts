@@ -22,7 +22,10 @@ export const CloseStruct = new beet.BeetArgsStruct<{ instructionDiscriminator: n
  *
  * @property [_writable_] jsonAccount The account to store the metadata in.
  * @property [_writable_] jsonMetadataAccount The account to store the json account's metadata in.
- * @property [_writable_, **signer**] payer The account that will pay for the transaction and rent.
+ * @property [**signer**] authority The authority approving the change.
+ * @property [_writable_] rentDestination The account that will receive the rent.
+ * @property [_writable_] bufferAccount The upload buffer, closed if an upload is in progress.
+ * @property [_writable_] schemaAccount (optional) The attached schema account, required when a schema is set.
  * @category Instructions
  * @category Close
  * @category generated
@@ -30,8 +33,11 @@ export const CloseStruct = new beet.BeetArgsStruct<{ instructionDiscriminator: n
 export type CloseInstructionAccounts = {
   jsonAccount: web3.PublicKey;
   jsonMetadataAccount: web3.PublicKey;
-  payer: web3.PublicKey;
+  authority: web3.PublicKey;
+  rentDestination: web3.PublicKey;
   systemProgram?: web3.PublicKey;
+  bufferAccount: web3.PublicKey;
+  schemaAccount?: web3.PublicKey;
 };
 
 export const closeInstructionDiscriminator = 1;
@@ -63,17 +69,35 @@ export function createCloseInstruction(
       isSigner: false,
     },
     {
-      pubkey: accounts.payer,
-      isWritable: true,
+      pubkey: accounts.authority,
+      isWritable: false,
       isSigner: true,
     },
+    {
+      pubkey: accounts.rentDestination,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.bufferAccount,
+      isWritable: true,
+      isSigner: false,
+    },
   ];
 
+  if (accounts.schemaAccount != null) {
+    keys.push({
+      pubkey: accounts.schemaAccount,
+      isWritable: true,
+      isSigner: false,
+    });
+  }
+
   const ix = new web3.TransactionInstruction({
     programId,
     keys,
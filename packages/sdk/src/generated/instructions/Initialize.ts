@@ -22,6 +22,7 @@ export const InitializeStruct = new beet.BeetArgsStruct<{ instructionDiscriminat
  *
  * @property [_writable_, **signer**] jsonAccount The account to store the metadata in.
  * @property [_writable_] jsonMetadataAccount The account to store the json account's metadata in.
+ * @property [] authority The first authority of the JSON account, which needn't sign.
  * @property [_writable_, **signer**] payer The account that will pay for the transaction and rent.
  * @category Instructions
  * @category Initialize
@@ -30,6 +31,7 @@ export const InitializeStruct = new beet.BeetArgsStruct<{ instructionDiscriminat
 export type InitializeInstructionAccounts = {
   jsonAccount: web3.PublicKey;
   jsonMetadataAccount: web3.PublicKey;
+  authority: web3.PublicKey;
   payer: web3.PublicKey;
   systemProgram?: web3.PublicKey;
 };
@@ -62,6 +64,11 @@ export function createInitializeInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.authority,
+      isWritable: false,
+      isSigner: false,
+    },
     {
       pubkey: accounts.payer,
       isWritable: true,
@@ -36,14 +36,18 @@ export const RemoveAuthorityStruct = new beet.BeetArgsStruct<
 /**
  * Accounts required by the _RemoveAuthority_ instruction
  *
- * @property [_writable_] jsonMetadataAccount The account to store the metadata's metadata in.
+ * @property [] jsonAccount The account to store the metadata in.
+ * @property [_writable_] jsonMetadataAccount The account to store the json account's metadata in.
+ * @property [**signer**] authority The authority approving the change.
  * @property [_writable_, **signer**] payer The account that will pay for the transaction and rent.
  * @category Instructions
  * @category RemoveAuthority
  * @category generated
  */
 export type RemoveAuthorityInstructionAccounts = {
+  jsonAccount: web3.PublicKey;
   jsonMetadataAccount: web3.PublicKey;
+  authority: web3.PublicKey;
   payer: web3.PublicKey;
   systemProgram?: web3.PublicKey;
 };
@@ -70,11 +74,21 @@ export function createRemoveAuthorityInstruction(
     ...args,
   });
   const keys: web3.AccountMeta[] = [
+    {
+      pubkey: accounts.jsonAccount,
+      isWritable: false,
+      isSigner: false,
+    },
     {
       pubkey: accounts.jsonMetadataAccount,
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.authority,
+      isWritable: false,
+      isSigner: true,
+    },
     {
       pubkey: accounts.payer,
       isWritable: true,
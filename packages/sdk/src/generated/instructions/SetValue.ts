@@ -38,7 +38,10 @@ export const SetValueStruct = new beet.FixableBeetArgsStruct<
  *
  * @property [_writable_] jsonAccount The account to store the metadata in.
  * @property [_writable_] jsonMetadataAccount The account to store the json account's metadata in.
+ * @property [**signer**] authority The authority approving the change.
  * @property [_writable_, **signer**] payer The account that will pay for the transaction and rent.
+ * @property [_writable_] rentDestination The account that will receive any rent the document no longer needs.
+ * @property [] schemaAccount (optional) The attached schema account, required when a schema is set.
  * @category Instructions
  * @category SetValue
  * @category generated
@@ -46,8 +49,11 @@ export const SetValueStruct = new beet.FixableBeetArgsStruct<
 export type SetValueInstructionAccounts = {
   jsonAccount: web3.PublicKey;
   jsonMetadataAccount: web3.PublicKey;
+  authority: web3.PublicKey;
   payer: web3.PublicKey;
+  rentDestination: web3.PublicKey;
   systemProgram?: web3.PublicKey;
+  schemaAccount?: web3.PublicKey;
 };
 
 export const setValueInstructionDiscriminator = 2;
@@ -82,11 +88,21 @@ export function createSetValueInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.authority,
+      isWritable: false,
+      isSigner: true,
+    },
     {
       pubkey: accounts.payer,
       isWritable: true,
       isSigner: true,
     },
+    {
+      pubkey: accounts.rentDestination,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
       isWritable: false,
@@ -94,6 +110,14 @@ export function createSetValueInstruction(
     },
   ];
 
+  if (accounts.schemaAccount != null) {
+    keys.push({
+      pubkey: accounts.schemaAccount,
+      isWritable: false,
+      isSigner: false,
+    });
+  }
+
   const ix = new web3.TransactionInstruction({
     programId,
     keys,
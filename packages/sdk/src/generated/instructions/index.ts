@@ -1,6 +1,26 @@
+export * from './AcceptAuthority';
 export * from './AddAuthority';
 export * from './AppendValue';
+export * from './Attest';
+export * from './BeginUpload';
+export * from './CancelProposal';
+export * from './CancelUpload';
 export * from './Close';
+export * from './CommitUpload';
+export * from './DeleteAtPointer';
+export * from './Freeze';
 export * from './Initialize';
+export * from './InitializeWithSeed';
+export * from './Migrate';
+export * from './PatchValue';
+export * from './ProposeAuthority';
 export * from './RemoveAuthority';
+export * from './RemoveSchema';
+export * from './RenounceAll';
+export * from './SetAtPointer';
+export * from './SetEncoding';
+export * from './SetSchema';
+export * from './SetThreshold';
 export * from './SetValue';
+export * from './VerifyHash';
+export * from './WriteChunk';
@@ -10,13 +10,19 @@ import * as beetSolana from '@metaplex-foundation/beet-solana';
 import * as beet from '@metaplex-foundation/beet';
 export type AddAuthorityArgs = {
   newAuthority: web3.PublicKey;
+  roles: number;
+  paths: string[];
 };
 
 /**
  * @category userTypes
  * @category generated
  */
-export const addAuthorityArgsBeet = new beet.BeetArgsStruct<AddAuthorityArgs>(
-  [['newAuthority', beetSolana.publicKey]],
+export const addAuthorityArgsBeet = new beet.FixableBeetArgsStruct<AddAuthorityArgs>(
+  [
+    ['newAuthority', beetSolana.publicKey],
+    ['roles', beet.u8],
+    ['paths', beet.array(beet.utf8String)],
+  ],
   'AddAuthorityArgs',
 );
@@ -8,6 +8,7 @@
 import * as beet from '@metaplex-foundation/beet';
 export type AppendValueArgs = {
   value: string;
+  expectedRevision: beet.COption<beet.bignum>;
 };
 
 /**
@@ -15,6 +16,9 @@ export type AppendValueArgs = {
  * @category generated
  */
 export const appendValueArgsBeet = new beet.FixableBeetArgsStruct<AppendValueArgs>(
-  [['value', beet.utf8String]],
+  [
+    ['value', beet.utf8String],
+    ['expectedRevision', beet.coption(beet.u64)],
+  ],
   'AppendValueArgs',
 );
@@ -8,6 +8,7 @@
 import * as beet from '@metaplex-foundation/beet';
 export type SetValueArgs = {
   value: string;
+  expectedRevision: beet.COption<beet.bignum>;
 };
 
 /**
@@ -15,6 +16,9 @@ export type SetValueArgs = {
  * @category generated
  */
 export const setValueArgsBeet = new beet.FixableBeetArgsStruct<SetValueArgs>(
-  [['value', beet.utf8String]],
+  [
+    ['value', beet.utf8String],
+    ['expectedRevision', beet.coption(beet.u64)],
+  ],
   'SetValueArgs',
 );
@@ -1,4 +1,21 @@
 export * from './AddAuthorityArgs';
 export * from './AppendValueArgs';
+export * from './AuthorityEntry';
+export * from './BeginUploadArgs';
+export * from './CancelProposalArgs';
+export * from './CommitUploadArgs';
+export * from './DeleteAtPointerArgs';
+export * from './Encoding';
+export * from './InitializeWithSeedArgs';
+export * from './JsonEvent';
+export * from './LegacyJsonMetadata';
+export * from './PatchValueArgs';
+export * from './ProposeAuthorityArgs';
 export * from './RemoveAuthorityArgs';
+export * from './SetAtPointerArgs';
+export * from './SetEncodingArgs';
+export * from './SetSchemaArgs';
+export * from './SetThresholdArgs';
 export * from './SetValueArgs';
+export * from './VerifyHashArgs';
+export * from './WriteChunkArgs';
@@ -2,6 +2,10 @@ import { PublicKey } from '@solana/web3.js';
 import { PROGRAM_ADDRESS } from './generated';
 
 export const PREFIX = 'JSON';
+export const BUFFER = 'buffer';
+export const SCHEMA = 'schema';
+export const ATTESTATION = 'attestation';
+export const JSON_ACCOUNT = 'json_account';
 
 export function find_metadata_account(json_account: PublicKey) {
   return PublicKey.findProgramAddressSync(
@@ -9,3 +13,54 @@ export function find_metadata_account(json_account: PublicKey) {
     new PublicKey(PROGRAM_ADDRESS),
   );
 }
+
+export function find_buffer_account(json_account: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(PREFIX),
+      new PublicKey(PROGRAM_ADDRESS).toBuffer(),
+      json_account.toBuffer(),
+      Buffer.from(BUFFER),
+    ],
+    new PublicKey(PROGRAM_ADDRESS),
+  );
+}
+
+export function find_schema_account(json_account: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(PREFIX),
+      new PublicKey(PROGRAM_ADDRESS).toBuffer(),
+      json_account.toBuffer(),
+      Buffer.from(SCHEMA),
+    ],
+    new PublicKey(PROGRAM_ADDRESS),
+  );
+}
+
+export function find_attestation_account(json_account: PublicKey, attester: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(PREFIX),
+      new PublicKey(PROGRAM_ADDRESS).toBuffer(),
+      json_account.toBuffer(),
+      Buffer.from(ATTESTATION),
+      attester.toBuffer(),
+    ],
+    new PublicKey(PROGRAM_ADDRESS),
+  );
+}
+
+export function find_json_account(owner: PublicKey, seed: string, mint?: PublicKey) {
+  const seeds = [
+    Buffer.from(PREFIX),
+    new PublicKey(PROGRAM_ADDRESS).toBuffer(),
+    Buffer.from(JSON_ACCOUNT),
+    owner.toBuffer(),
+  ];
+  if (mint != null) {
+    seeds.push(mint.toBuffer());
+  }
+  seeds.push(Buffer.from(seed));
+  return PublicKey.findProgramAddressSync(seeds, new PublicKey(PROGRAM_ADDRESS));
+}
@@ -85,6 +85,7 @@ test('AppendValue', async (t) => {
   const init_ix = createInitializeInstruction({
     jsonAccount: jsonAccountKeypair.publicKey,
     jsonMetadataAccount: jsonMetadataAccount[0],
+    authority: payer.publicKey,
     payer: payer.publicKey,
   });
 
@@ -92,11 +93,14 @@ test('AppendValue', async (t) => {
     {
       jsonAccount: jsonAccountKeypair.publicKey,
       jsonMetadataAccount: jsonMetadataAccount[0],
+      authority: payer.publicKey,
       payer: payer.publicKey,
+      rentDestination: payer.publicKey,
     },
     {
       setValueArgs: {
         value: '{"name": "Bread On-Chain", "symbol": "BREAD"}',
+        expectedRevision: null,
       },
     },
   );
@@ -105,12 +109,15 @@ test('AppendValue', async (t) => {
     {
       jsonAccount: jsonAccountKeypair.publicKey,
       jsonMetadataAccount: jsonMetadataAccount[0],
+      authority: payer.publicKey,
       payer: payer.publicKey,
+      rentDestination: payer.publicKey,
     },
     {
       setValueArgs: {
         value:
           '{"description": "A bread! But on-chain!", "seller_fee_basis_points": 500, "external_url": "https://breadheads.io"}',
+        expectedRevision: null,
       },
     },
   );
@@ -139,7 +146,9 @@ test('AppendValue', async (t) => {
   console.log(jsonMetadataAccountData);
   t.assert(jsonMetadataAccountData.bump == jsonMetadataAccount[1], 'bump is correct');
   t.assert(jsonMetadataAccountData.mutable == true, 'Account is mutable');
-  const authorities = jsonMetadataAccountData.authorities.map((authority) => authority.toString());
+  const authorities = jsonMetadataAccountData.authorities.map((authority) =>
+    authority.key.toString(),
+  );
   console.log(authorities);
   console.log(payer.publicKey);
   t.assert(authorities.length == 1, 'There is one authority');
@@ -149,12 +158,15 @@ test('AppendValue', async (t) => {
     {
       jsonAccount: jsonAccountKeypair.publicKey,
       jsonMetadataAccount: jsonMetadataAccount[0],
+      authority: payer.publicKey,
       payer: payer.publicKey,
+      rentDestination: payer.publicKey,
     },
     {
       setValueArgs: {
         value:
           '{"image": "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAACAAAAAgCAMAAABEpIrGAAAAPFBMVEUAAADoijZnOTEODAyyi3j/4Lfisn78pXCwWyz4xTr////icoX49kRhpT9Hcji0ICpCpFk9b0MUNGQoXMSCKWhnAAAAAXRSTlMAQObYZgAAAP9JREFUOMuNkAmugzAMRDHO4iwQaO9/1z9Oot8FI3UQGYt5tiOW3xRcwHmcztm5C0ocxxFsAp+BnOcBc/YAPLWeQf3mBkFqrbC7OzgRqbpp7boSpYigv+cW00opo98mWmtl9tvEo7XRTxoQ4f0Eno/eT5Ag6f454enczFW9Wr//lDZumbry9gJeWmljRkLD1/UKZOZdoJ0503XAQrQj78ROAC7SO+KdZgPMPA4b4DcZwExEZnFDSM5i5lBkwvKcCcbRAHykf0VvAMn7wUQUyQAopegj5GNKZK9IYCIOe4X3mE1ItbAAfEU7c4paLYY8pIC6lWPEXGEPgHQ2EeL3/A89Zgj/88QojwAAAABJRU5ErkJggg=="}',
+        expectedRevision: null,
       },
     },
   );
@@ -14,6 +14,7 @@ test('Initialize', async (t) => {
   const init_ix = createInitializeInstruction({
     jsonAccount: jsonAccountKeypair.publicKey,
     jsonMetadataAccount: jsonMetadataAccount[0],
+    authority: payer.publicKey,
     payer: payer.publicKey,
   });
 
@@ -41,7 +42,9 @@ test('Initialize', async (t) => {
   console.log(jsonMetadataAccountData);
   t.assert(jsonMetadataAccountData.bump == jsonMetadataAccount[1], 'bump is correct');
   t.assert(jsonMetadataAccountData.mutable == true, 'Account is mutable');
-  const authorities = jsonMetadataAccountData.authorities.map((authority) => authority.toString());
+  const authorities = jsonMetadataAccountData.authorities.map((authority) =>
+    authority.key.toString(),
+  );
   console.log(authorities);
   console.log(payer.publicKey);
   t.assert(authorities.length == 1, 'There is one authority');
@@ -85,6 +85,7 @@ test('SetValue', async (t) => {
   const init_ix = createInitializeInstruction({
     jsonAccount: jsonAccountKeypair.publicKey,
     jsonMetadataAccount: jsonMetadataAccount[0],
+    authority: payer.publicKey,
     payer: payer.publicKey,
   });
 
@@ -92,11 +93,14 @@ test('SetValue', async (t) => {
     {
       jsonAccount: jsonAccountKeypair.publicKey,
       jsonMetadataAccount: jsonMetadataAccount[0],
+      authority: payer.publicKey,
       payer: payer.publicKey,
+      rentDestination: payer.publicKey,
     },
     {
       setValueArgs: {
         value: '{"name": "Bread On-Chain", "symbol": "BREAD"}',
+        expectedRevision: null,
       },
     },
   );
@@ -105,12 +109,15 @@ test('SetValue', async (t) => {
     {
       jsonAccount: jsonAccountKeypair.publicKey,
       jsonMetadataAccount: jsonMetadataAccount[0],
+      authority: payer.publicKey,
       payer: payer.publicKey,
+      rentDestination: payer.publicKey,
     },
     {
       setValueArgs: {
         value:
           '{"description": "A bread! But on-chain!", "seller_fee_basis_points": 500, "external_url": "https://breadheads.io"}',
+        expectedRevision: null,
       },
     },
   );
@@ -139,7 +146,9 @@ test('SetValue', async (t) => {
   console.log(jsonMetadataAccountData);
   t.assert(jsonMetadataAccountData.bump == jsonMetadataAccount[1], 'bump is correct');
   t.assert(jsonMetadataAccountData.mutable == true, 'Account is mutable');
-  const authorities = jsonMetadataAccountData.authorities.map((authority) => authority.toString());
+  const authorities = jsonMetadataAccountData.authorities.map((authority) =>
+    authority.key.toString(),
+  );
   console.log(authorities);
   console.log(payer.publicKey);
   t.assert(authorities.length == 1, 'There is one authority');
@@ -0,0 +1,184 @@
+//! A program that calls solana-json through `solana_json::cpi`, signing as its `AUTHORITY` PDA.
+//!
+//! It exists to exercise the CPI wrappers against the deployed program. Run its tests with
+//! `cargo test-bpf --manifest-path cpi-caller/Cargo.toml`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_json::{
+    cpi::{self, AuthorityAccounts, CloseAccounts, UploadAccounts, WriteAccounts},
+    instruction::{
+        AddAuthorityArgs, AppendValueArgs, BeginUploadArgs, CommitUploadArgs, SetValueArgs,
+        WriteChunkArgs,
+    },
+    pda::{find_buffer_account, find_metadata_account},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+solana_program::declare_id!("JsonCpiCa11er111111111111111111111111111111");
+
+/// The seed of the PDA the caller signs as.
+pub const AUTHORITY: &[u8] = b"authority";
+
+pub fn find_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AUTHORITY], &ID)
+}
+
+/// What the caller asks the JSON program to do, signing as its PDA.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub enum CallerInstruction {
+    SetValue(String),
+    AppendValue(String),
+    AcceptAuthority,
+    AddAuthority(AddAuthorityArgs),
+    /// Uploads the document with `BeginUpload`, `WriteChunk` and `CommitUpload`.
+    Upload(String),
+    Close,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let json_program = next_account_info(accounts)?;
+    let json_account = next_account_info(accounts)?;
+    let json_metadata_account = next_account_info(accounts)?;
+    let authority = next_account_info(accounts)?;
+    let payer = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    let buffer_account = next_account_info(accounts)?;
+
+    let (_, bump) = find_authority();
+    let signers_seeds: &[&[&[u8]]] = &[&[AUTHORITY, &[bump]]];
+    let instruction = CallerInstruction::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    let write_accounts = || WriteAccounts {
+        json_program,
+        json_account,
+        json_metadata_account,
+        authority,
+        payer,
+        rent_destination: payer,
+        system_program,
+        schema_account: None,
+        co_signers: &[],
+    };
+    let authority_accounts = || AuthorityAccounts {
+        json_program,
+        json_account,
+        json_metadata_account,
+        authority,
+        payer,
+        system_program,
+        co_signers: &[],
+    };
+    let upload_accounts = || UploadAccounts {
+        json_program,
+        json_account,
+        json_metadata_account,
+        buffer_account,
+        authority,
+        payer,
+        rent_destination: payer,
+        system_program,
+        schema_account: None,
+        co_signers: &[],
+    };
+
+    match instruction {
+        CallerInstruction::SetValue(value) => cpi::set_value(
+            write_accounts(),
+            SetValueArgs {
+                value,
+                expected_revision: None,
+            },
+            signers_seeds,
+        ),
+        CallerInstruction::AppendValue(value) => cpi::append_value(
+            write_accounts(),
+            AppendValueArgs {
+                value,
+                expected_revision: None,
+            },
+            signers_seeds,
+        ),
+        CallerInstruction::AcceptAuthority => {
+            cpi::accept_authority(authority_accounts(), signers_seeds)
+        }
+        CallerInstruction::AddAuthority(args) => {
+            cpi::add_authority(authority_accounts(), args, signers_seeds)
+        }
+        CallerInstruction::Upload(value) => {
+            let bytes = value.into_bytes();
+            cpi::begin_upload(
+                upload_accounts(),
+                BeginUploadArgs {
+                    size: bytes.len() as u32,
+                },
+                signers_seeds,
+            )?;
+            cpi::write_chunk(
+                upload_accounts(),
+                WriteChunkArgs { offset: 0, bytes },
+                signers_seeds,
+            )?;
+            cpi::commit_upload(
+                upload_accounts(),
+                CommitUploadArgs {
+                    merge: false,
+                    expected_revision: None,
+                },
+                signers_seeds,
+            )
+        }
+        CallerInstruction::Close => cpi::close(
+            CloseAccounts {
+                json_program,
+                json_account,
+                json_metadata_account,
+                authority,
+                rent_destination: payer,
+                system_program,
+                buffer_account,
+                schema_account: None,
+                co_signers: &[],
+            },
+            signers_seeds,
+        ),
+    }
+}
+
+/// Builds an instruction asking the caller to act on `json_account`.
+pub fn instruction(
+    json_account: Pubkey,
+    payer: Pubkey,
+    instruction: CallerInstruction,
+) -> Instruction {
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let (buffer_account, _) = find_buffer_account(&json_account);
+    Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new_readonly(solana_json::ID, false),
+            AccountMeta::new(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(find_authority().0, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(buffer_account, false),
+        ],
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
@@ -0,0 +1,229 @@
+#![cfg(feature = "test-bpf")]
+
+use serde_json::json;
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{self, AddAuthorityArgs, ProposeAuthorityArgs},
+    pda::{find_buffer_account, find_metadata_account},
+    state::{JsonMetadata, ROLE_ADMIN, ROLE_APPENDER, ROLE_WRITER},
+};
+use solana_json_cpi_caller::{find_authority, CallerInstruction};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+fn program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::new("solana_json", solana_json::ID, None);
+    program_test.add_program("solana_json_cpi_caller", solana_json_cpi_caller::ID, None);
+    program_test
+}
+
+async fn process(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+    context.last_blockhash = last_blockhash;
+
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        last_blockhash,
+    );
+    context.banks_client.process_transaction(transaction).await
+}
+
+fn assert_error(result: Result<(), BanksClientError>, error: OnchainMetadataError) {
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(code, error as u32)
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+async fn initialize(context: &mut ProgramTestContext) -> Pubkey {
+    let json_account = Keypair::new();
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let payer = context.payer.pubkey();
+    let initialize = instruction::initialize(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+    );
+    process(context, &[initialize], &[&json_account])
+        .await
+        .unwrap();
+    json_account.pubkey()
+}
+
+async fn json_data(context: &mut ProgramTestContext, json_account: Pubkey) -> serde_json::Value {
+    let account = context
+        .banks_client
+        .get_account(json_account)
+        .await
+        .unwrap()
+        .unwrap();
+    serde_json::from_slice(&account.data).unwrap()
+}
+
+fn call(context: &ProgramTestContext, json_account: Pubkey, ix: CallerInstruction) -> Instruction {
+    solana_json_cpi_caller::instruction(json_account, context.payer.pubkey(), ix)
+}
+
+#[tokio::test]
+async fn test_cpi_with_pda_authority() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+    let (pda_authority, _) = find_authority();
+
+    // The caller's PDA isn't an authority yet.
+    let set_value = call(
+        &context,
+        json_account,
+        CallerInstruction::SetValue(r#"{"name": "Token"}"#.to_string()),
+    );
+    let result = process(&mut context, &[set_value.clone()], &[]).await;
+    assert_error(result, OnchainMetadataError::InvalidAuthority);
+
+    let add_authority = instruction::add_authority(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        AddAuthorityArgs {
+            new_authority: pda_authority,
+            roles: ROLE_WRITER | ROLE_APPENDER,
+            paths: vec![],
+        },
+    );
+    process(&mut context, &[add_authority], &[]).await.unwrap();
+
+    process(&mut context, &[set_value], &[]).await.unwrap();
+    let append_value = call(
+        &context,
+        json_account,
+        CallerInstruction::AppendValue(r#"{"name": "!"}"#.to_string()),
+    );
+    process(&mut context, &[append_value], &[]).await.unwrap();
+
+    assert_eq!(
+        json_data(&mut context, json_account).await,
+        json!({"name": "Token!"})
+    );
+}
+
+#[tokio::test]
+async fn test_cpi_upload() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+
+    let add_authority = instruction::add_authority(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        AddAuthorityArgs {
+            new_authority: find_authority().0,
+            roles: ROLE_WRITER,
+            paths: vec![],
+        },
+    );
+    let upload = call(
+        &context,
+        json_account,
+        CallerInstruction::Upload(r#"{"name": "Uploaded"}"#.to_string()),
+    );
+    process(&mut context, &[add_authority, upload], &[])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        json_data(&mut context, json_account).await,
+        json!({"name": "Uploaded"})
+    );
+    let (buffer_account, _) = find_buffer_account(&json_account);
+    assert!(context
+        .banks_client
+        .get_account(buffer_account)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_cpi_admin() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+    let writer = Keypair::new().pubkey();
+
+    // Admin rights have to be proposed, so the PDA accepts them through CPI.
+    let propose_authority = instruction::propose_authority(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        ProposeAuthorityArgs {
+            new_authority: find_authority().0,
+            roles: ROLE_ADMIN,
+            paths: vec![],
+        },
+    );
+    let accept_authority = call(&context, json_account, CallerInstruction::AcceptAuthority);
+    let add_authority = call(
+        &context,
+        json_account,
+        CallerInstruction::AddAuthority(AddAuthorityArgs {
+            new_authority: writer,
+            roles: ROLE_WRITER,
+            paths: vec![],
+        }),
+    );
+    process(
+        &mut context,
+        &[propose_authority, accept_authority, add_authority],
+        &[],
+    )
+    .await
+    .unwrap();
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert!(json_metadata.has_role(&writer, ROLE_WRITER));
+
+    let close = call(&context, json_account, CallerInstruction::Close);
+    process(&mut context, &[close], &[]).await.unwrap();
+    for account in [json_account, json_metadata_account] {
+        assert!(context
+            .banks_client
+            .get_account(account)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,56 @@
+//! A program that reads one number from a JSON account, either with `solana_json::reader` or by
+//! parsing the whole document, so the compute units of both can be compared.
+//!
+//! The accounts are the JSON account and its metadata account, which both modes check with
+//! [`reader::load`] as a consuming program would. The instruction data is a mode byte followed by
+//! a JSON Pointer. Mode 0 uses the reader and any other mode parses the document with
+//! `serde_json`. Run the comparison with
+//! `cargo test-bpf --manifest-path reader-bench/Cargo.toml`.
+
+use solana_json::{error::OnchainMetadataError, reader};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+solana_program::declare_id!("JsonReaderBench1111111111111111111111111111");
+
+/// Reads the number from the reader.
+pub const MODE_READER: u8 = 0;
+/// Reads the number from a fully parsed `serde_json::Value`.
+pub const MODE_PARSE: u8 = 1;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let json_account = next_account_info(accounts_iter)?;
+    let json_metadata_account = next_account_info(accounts_iter)?;
+    let (mode, pointer) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    let pointer = std::str::from_utf8(pointer).map_err(|_| ProgramError::InvalidInstructionData)?;
+    let json_data = reader::load(json_account, json_metadata_account)?;
+
+    let value: u64 = if *mode == MODE_READER {
+        reader::get_as(&json_data, pointer)?
+    } else {
+        let doc: serde_json::Value =
+            serde_json::from_slice(&json_data).map_err(|_| OnchainMetadataError::InvalidJson)?;
+        doc.pointer(pointer)
+            .ok_or(OnchainMetadataError::PointerNotFound)?
+            .as_u64()
+            .ok_or(OnchainMetadataError::TypeMismatch)?
+    };
+
+    msg!("Value: {}", value);
+    Ok(())
+}
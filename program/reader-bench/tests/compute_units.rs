@@ -0,0 +1,126 @@
+#![cfg(feature = "test-bpf")]
+
+use solana_json::{
+    instruction::{self, SetValueArgs},
+    pda::find_metadata_account,
+};
+use solana_json_reader_bench::{MODE_PARSE, MODE_READER};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+fn read_value(
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    mode: u8,
+    pointer: &str,
+) -> Instruction {
+    let mut data = vec![mode];
+    data.extend_from_slice(pointer.as_bytes());
+    Instruction {
+        program_id: solana_json_reader_bench::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new_readonly(json_metadata_account, false),
+        ],
+        data,
+    }
+}
+
+async fn units_consumed(context: &mut ProgramTestContext, ix: Instruction) -> u64 {
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    simulation.result.unwrap().unwrap();
+    simulation.simulation_details.unwrap().units_consumed
+}
+
+#[tokio::test]
+async fn test_reader_compute_units() {
+    let mut program_test = ProgramTest::new("solana_json", solana_json::ID, None);
+    program_test.add_program(
+        "solana_json_reader_bench",
+        solana_json_reader_bench::ID,
+        None,
+    );
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let json_account = Keypair::new();
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let attributes: Vec<_> = (0..16)
+        .map(|i| serde_json::json!({"trait_type": format!("Trait {}", i), "value": i}))
+        .collect();
+    let value = serde_json::json!({"name": "Token", "attributes": attributes}).to_string();
+    let instructions = [
+        instruction::initialize(
+            solana_json::ID,
+            json_account.pubkey(),
+            json_metadata_account,
+            payer,
+            payer,
+        ),
+        instruction::set_value(
+            solana_json::ID,
+            json_account.pubkey(),
+            json_metadata_account,
+            payer,
+            payer,
+            payer,
+            SetValueArgs {
+                value,
+                expected_revision: None,
+            },
+        ),
+    ];
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&payer),
+        &[&context.payer, &json_account],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let pointer = "/attributes/3/value";
+    let reader = units_consumed(
+        &mut context,
+        read_value(
+            json_account.pubkey(),
+            json_metadata_account,
+            MODE_READER,
+            pointer,
+        ),
+    )
+    .await;
+    let parse = units_consumed(
+        &mut context,
+        read_value(
+            json_account.pubkey(),
+            json_metadata_account,
+            MODE_PARSE,
+            pointer,
+        ),
+    )
+    .await;
+
+    println!("{}: reader {} CU, full parse {} CU", pointer, reader, parse);
+    assert!(reader < parse);
+}
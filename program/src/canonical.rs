@@ -0,0 +1,149 @@
+use std::fmt::Write;
+
+use serde_json::{Number, Value};
+use solana_program::{hash::hashv, program_error::ProgramError};
+
+use crate::error::OnchainMetadataError;
+
+/// The largest integer magnitude an IEEE 754 double holds exactly. Above it, distinct integers
+/// would be written as the same number.
+const MAX_EXACT_INTEGER: u64 = 1 << 53;
+
+/// Serializes `value` as RFC 8785 canonical JSON.
+///
+/// Object members are sorted by the UTF-16 code units of their names, strings use the minimal
+/// escaping of ECMAScript's `JSON.stringify` and numbers are written as IEEE 754 doubles in the
+/// shortest form that ECMAScript would produce, so `12.0` and `12` are both stored as `12`.
+///
+/// Fails with `InvalidJson` if `value` holds an integer larger than 2^53, since rounding it to a
+/// double would change the document.
+pub fn to_vec(value: &Value) -> Result<Vec<u8>, ProgramError> {
+    let mut out = String::new();
+    write_value(&mut out, value, true)?;
+    Ok(out.into_bytes())
+}
+
+/// Returns the SHA-256 hash of the canonical form of `value`.
+///
+/// This is the hash `JsonMetadata` records, so it only depends on the document and not on the
+/// encoding it's stored in. Integers larger than 2^53, which only accounts not stored as
+/// canonical JSON can hold, are hashed with their exact digits instead of being rounded.
+pub fn hash(value: &Value) -> [u8; 32] {
+    let mut out = String::new();
+    // Only strict serialization fails.
+    let _ = write_value(&mut out, value, false);
+    hashv(&[out.as_bytes()]).to_bytes()
+}
+
+/// Writes `value` to `out`, failing on inexact integers when `strict` and writing their exact
+/// digits otherwise.
+fn write_value(out: &mut String, value: &Value, strict: bool) -> Result<(), ProgramError> {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) if !is_exact(n) => {
+            if strict {
+                return Err(OnchainMetadataError::InvalidJson.into());
+            }
+            out.push_str(&n.to_string())
+        }
+        Value::Number(n) => write_number(out, n.as_f64().unwrap_or_default()),
+        Value::String(s) => write_string(out, s),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(out, item, strict)?;
+            }
+            out.push(']');
+        }
+        Value::Object(members) => {
+            let mut members: Vec<_> = members.iter().collect();
+            members.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+
+            out.push('{');
+            for (i, (key, value)) in members.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(out, key);
+                out.push(':');
+                write_value(out, value, strict)?;
+            }
+            out.push('}');
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns whether `n` survives the conversion to a double. Non-integers were parsed as doubles
+/// to begin with.
+fn is_exact(n: &Number) -> bool {
+    match (n.as_u64(), n.as_i64()) {
+        (Some(u), _) => u <= MAX_EXACT_INTEGER,
+        (None, Some(i)) => i.unsigned_abs() <= MAX_EXACT_INTEGER,
+        (None, None) => true,
+    }
+}
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{08}' => out.push_str("\\b"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\u{0c}' => out.push_str("\\f"),
+            '\r' => out.push_str("\\r"),
+            c if c < ' ' => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Writes `n` the way ECMAScript's `Number.prototype.toString` does.
+fn write_number(out: &mut String, n: f64) {
+    if n == 0.0 {
+        out.push('0');
+        return;
+    }
+    if n < 0.0 {
+        out.push('-');
+    }
+
+    // Rust's exponential formatting gives the shortest digits that round-trip, which is what
+    // ECMAScript uses too. Only the placement of the decimal point and exponent differs.
+    let exponential = format!("{:e}", n.abs());
+    let (mantissa, exponent) = exponential.split_once('e').unwrap_or((&exponential, "0"));
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let k = digits.len() as i32;
+    let n = exponent.parse::<i32>().unwrap_or_default() + 1;
+
+    if k <= n && n <= 21 {
+        out.push_str(&digits);
+        out.extend(std::iter::repeat('0').take((n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        out.push_str(&digits[..n as usize]);
+        out.push('.');
+        out.push_str(&digits[n as usize..]);
+    } else if -6 < n && n <= 0 {
+        out.push_str("0.");
+        out.extend(std::iter::repeat('0').take(-n as usize));
+        out.push_str(&digits);
+    } else {
+        out.push_str(&digits[..1]);
+        if k > 1 {
+            out.push('.');
+            out.push_str(&digits[1..]);
+        }
+        let _ = write!(out, "e{}{}", if n > 0 { '+' } else { '-' }, (n - 1).abs());
+    }
+}
@@ -0,0 +1,573 @@
+//! An async client for reading and writing JSON accounts through a [`BanksClient`] or an
+//! [`RpcClient`].
+
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use solana_banks_client::{BanksClient, BanksClientError};
+use solana_client::{
+    client_error::ClientError as RpcClientError, nonblocking::rpc_client::RpcClient,
+};
+use solana_program::{
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use thiserror::Error;
+
+use crate::{
+    instruction::{
+        self, AddAuthorityArgs, AppendValueArgs, BeginUploadArgs, CommitUploadArgs,
+        DeleteAtPointerArgs, InitializeWithSeedArgs, PatchValueArgs, RemoveAuthorityArgs,
+        SetAtPointerArgs, SetEncodingArgs, SetValueArgs, WriteChunkArgs,
+    },
+    pda::{find_buffer_account, find_json_account, find_metadata_account},
+    state::{Encoding, JsonMetadata},
+    typed::{self, TypedError},
+};
+
+/// The number of document bytes sent in each `WriteChunk` transaction.
+pub const UPLOAD_CHUNK_SIZE: usize = 900;
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("account {0} does not exist")]
+    AccountNotFound(Pubkey),
+
+    #[error("the JSON metadata account could not be deserialized: {0}")]
+    InvalidMetadata(ProgramError),
+
+    #[error("the JSON data could not be serialized: {0}")]
+    InvalidJson(serde_json::Error),
+
+    #[error(transparent)]
+    Typed(#[from] TypedError),
+
+    #[error(transparent)]
+    Banks(Box<BanksClientError>),
+
+    #[error(transparent)]
+    Rpc(Box<RpcClientError>),
+}
+
+impl From<BanksClientError> for ClientError {
+    fn from(e: BanksClientError) -> Self {
+        ClientError::Banks(Box::new(e))
+    }
+}
+
+impl From<RpcClientError> for ClientError {
+    fn from(e: RpcClientError) -> Self {
+        ClientError::Rpc(Box::new(e))
+    }
+}
+
+/// What [`JsonAccountClient`] needs from a cluster: reading accounts and sending transactions.
+#[async_trait]
+pub trait Connection: Send {
+    /// Fetches the data of the account at `address`, or `None` if it doesn't exist.
+    async fn get_account_data(&mut self, address: &Pubkey) -> Result<Option<Vec<u8>>, ClientError>;
+
+    async fn get_latest_blockhash(&mut self) -> Result<Hash, ClientError>;
+
+    /// Sends `transaction` and waits for it to be confirmed.
+    async fn send_transaction(&mut self, transaction: Transaction) -> Result<(), ClientError>;
+}
+
+#[async_trait]
+impl Connection for BanksClient {
+    async fn get_account_data(&mut self, address: &Pubkey) -> Result<Option<Vec<u8>>, ClientError> {
+        Ok(self
+            .get_account(*address)
+            .await?
+            .map(|account| account.data))
+    }
+
+    async fn get_latest_blockhash(&mut self) -> Result<Hash, ClientError> {
+        Ok(BanksClient::get_latest_blockhash(self).await?)
+    }
+
+    async fn send_transaction(&mut self, transaction: Transaction) -> Result<(), ClientError> {
+        Ok(self.process_transaction(transaction).await?)
+    }
+}
+
+#[async_trait]
+impl Connection for RpcClient {
+    async fn get_account_data(&mut self, address: &Pubkey) -> Result<Option<Vec<u8>>, ClientError> {
+        let response = self
+            .get_account_with_commitment(address, self.commitment())
+            .await?;
+        Ok(response.value.map(|account| account.data))
+    }
+
+    async fn get_latest_blockhash(&mut self) -> Result<Hash, ClientError> {
+        Ok(RpcClient::get_latest_blockhash(self).await?)
+    }
+
+    async fn send_transaction(&mut self, transaction: Transaction) -> Result<(), ClientError> {
+        self.send_and_confirm_transaction(&transaction).await?;
+
+        Ok(())
+    }
+}
+
+/// A JSON account together with its metadata, as fetched from the cluster.
+#[derive(Clone, Debug)]
+pub struct JsonAccount {
+    pub address: Pubkey,
+    pub metadata_address: Pubkey,
+    pub metadata: JsonMetadata,
+    pub data: Vec<u8>,
+}
+
+impl JsonAccount {
+    /// Decodes the account data as an untyped JSON value, whatever its encoding.
+    pub fn value(&self) -> Result<serde_json::Value, ClientError> {
+        let encoding = self.metadata.encoding;
+        encoding
+            .deserialize(&self.data)
+            .map_err(|_| TypedError::NotDecodable(encoding).into())
+    }
+
+    /// Deserializes the account data into `T`, whatever its encoding.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, ClientError> {
+        Ok(typed::read_typed(&self.data, self.metadata.encoding)?)
+    }
+
+    /// Appends the accounts that the program requires after the fixed accounts of a write.
+    fn push_write_accounts(&self, ix: &mut Instruction) {
+        if let Some(schema) = self.metadata.schema {
+            ix.accounts.push(AccountMeta::new_readonly(schema, false));
+        }
+    }
+}
+
+/// Reads JSON accounts and sends the program's instructions, signing with `payer`.
+///
+/// The payer is also used as the authority for every write.
+pub struct JsonAccountClient<C = BanksClient> {
+    connection: C,
+    payer: Keypair,
+}
+
+impl<C: Connection> JsonAccountClient<C> {
+    pub fn new(connection: C, payer: Keypair) -> Self {
+        Self { connection, payer }
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    /// Fetches a JSON account and its metadata.
+    pub async fn fetch(&mut self, json_account: &Pubkey) -> Result<JsonAccount, ClientError> {
+        let (metadata_address, _) = find_metadata_account(json_account);
+        let data = self.fetch_data(json_account).await?;
+        let metadata_data = self.fetch_data(&metadata_address).await?;
+        let metadata = JsonMetadata::load(&metadata_data).map_err(ClientError::InvalidMetadata)?;
+
+        Ok(JsonAccount {
+            address: *json_account,
+            metadata_address,
+            metadata,
+            data,
+        })
+    }
+
+    /// Fetches a JSON account and parses it as an untyped JSON value.
+    pub async fn fetch_value(
+        &mut self,
+        json_account: &Pubkey,
+    ) -> Result<serde_json::Value, ClientError> {
+        self.fetch(json_account).await?.value()
+    }
+
+    /// Fetches a JSON account and deserializes it into `T`.
+    pub async fn fetch_as<T: DeserializeOwned>(
+        &mut self,
+        json_account: &Pubkey,
+    ) -> Result<T, ClientError> {
+        self.fetch(json_account).await?.deserialize()
+    }
+
+    /// Creates a JSON account at a new random address and returns the address.
+    pub async fn initialize(&mut self) -> Result<Pubkey, ClientError> {
+        let json_account = Keypair::new();
+        let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+        let ix = instruction::initialize(
+            crate::ID,
+            json_account.pubkey(),
+            json_metadata_account,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+        );
+        self.send(&[ix], &[&json_account]).await?;
+
+        Ok(json_account.pubkey())
+    }
+
+    /// Creates a JSON account at the address derived from `owner`, `seed` and `mint`, with
+    /// `owner` as its authority.
+    pub async fn initialize_with_seed(
+        &mut self,
+        owner: &Pubkey,
+        seed: &str,
+        mint: Option<Pubkey>,
+    ) -> Result<Pubkey, ClientError> {
+        let (json_account, _) = find_json_account(owner, seed, mint.as_ref());
+        let (json_metadata_account, _) = find_metadata_account(&json_account);
+        let ix = instruction::initialize_with_seed(
+            crate::ID,
+            json_account,
+            json_metadata_account,
+            *owner,
+            self.payer.pubkey(),
+            InitializeWithSeedArgs {
+                seed: seed.to_string(),
+                mint,
+            },
+        );
+        self.send(&[ix], &[]).await?;
+
+        Ok(json_account)
+    }
+
+    /// Recursively merges `value` into the document, removing keys set to `null`.
+    pub async fn set_value(
+        &mut self,
+        json_account: &Pubkey,
+        value: &serde_json::Value,
+    ) -> Result<(), ClientError> {
+        let account = self.fetch(json_account).await?;
+        let mut ix = instruction::set_value(
+            crate::ID,
+            account.address,
+            account.metadata_address,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            SetValueArgs {
+                value: value.to_string(),
+                expected_revision: None,
+            },
+        );
+        account.push_write_accounts(&mut ix);
+        self.send(&[ix], &[]).await
+    }
+
+    /// Recursively merges the JSON form of `value` into the document.
+    pub async fn set_typed<T: Serialize>(
+        &mut self,
+        json_account: &Pubkey,
+        value: &T,
+    ) -> Result<(), ClientError> {
+        let account = self.fetch(json_account).await?;
+        let mut ix = typed::set_typed(
+            crate::ID,
+            account.address,
+            account.metadata_address,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            value,
+        )?;
+        account.push_write_accounts(&mut ix);
+        self.send(&[ix], &[]).await
+    }
+
+    /// Appends strings and arrays in `value` to those already in the document.
+    pub async fn append_value(
+        &mut self,
+        json_account: &Pubkey,
+        value: &serde_json::Value,
+    ) -> Result<(), ClientError> {
+        let account = self.fetch(json_account).await?;
+        let mut ix = instruction::append_value(
+            crate::ID,
+            account.address,
+            account.metadata_address,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            AppendValueArgs {
+                value: value.to_string(),
+                expected_revision: None,
+            },
+        );
+        account.push_write_accounts(&mut ix);
+        self.send(&[ix], &[]).await
+    }
+
+    /// Applies an RFC 6902 JSON Patch document.
+    pub async fn patch_value(
+        &mut self,
+        json_account: &Pubkey,
+        patch: &serde_json::Value,
+    ) -> Result<(), ClientError> {
+        let account = self.fetch(json_account).await?;
+        let mut ix = instruction::patch_value(
+            crate::ID,
+            account.address,
+            account.metadata_address,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            PatchValueArgs {
+                patch: patch.to_string(),
+                expected_revision: None,
+            },
+        );
+        account.push_write_accounts(&mut ix);
+        self.send(&[ix], &[]).await
+    }
+
+    /// Writes `value` at the location referenced by a JSON Pointer.
+    pub async fn set_at_pointer(
+        &mut self,
+        json_account: &Pubkey,
+        pointer: &str,
+        value: &serde_json::Value,
+    ) -> Result<(), ClientError> {
+        let account = self.fetch(json_account).await?;
+        let mut ix = instruction::set_at_pointer(
+            crate::ID,
+            account.address,
+            account.metadata_address,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            SetAtPointerArgs {
+                pointer: pointer.to_string(),
+                value: value.to_string(),
+                expected_revision: None,
+            },
+        );
+        account.push_write_accounts(&mut ix);
+        self.send(&[ix], &[]).await
+    }
+
+    /// Removes the value at the location referenced by a JSON Pointer.
+    pub async fn delete_at_pointer(
+        &mut self,
+        json_account: &Pubkey,
+        pointer: &str,
+    ) -> Result<(), ClientError> {
+        let account = self.fetch(json_account).await?;
+        let mut ix = instruction::delete_at_pointer(
+            crate::ID,
+            account.address,
+            account.metadata_address,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            DeleteAtPointerArgs {
+                pointer: pointer.to_string(),
+                expected_revision: None,
+            },
+        );
+        account.push_write_accounts(&mut ix);
+        self.send(&[ix], &[]).await
+    }
+
+    /// Uploads a document of any size through a buffer account, then replaces or merges into
+    /// the current document.
+    pub async fn upload(
+        &mut self,
+        json_account: &Pubkey,
+        value: &serde_json::Value,
+        merge: bool,
+    ) -> Result<(), ClientError> {
+        let account = self.fetch(json_account).await?;
+        let (buffer_account, _) = find_buffer_account(json_account);
+        let bytes = serde_json::to_vec(value).map_err(ClientError::InvalidJson)?;
+
+        let ix = instruction::begin_upload(
+            crate::ID,
+            account.address,
+            account.metadata_address,
+            buffer_account,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            BeginUploadArgs {
+                size: bytes.len() as u32,
+            },
+        );
+        self.send(&[ix], &[]).await?;
+
+        for (i, chunk) in bytes.chunks(UPLOAD_CHUNK_SIZE).enumerate() {
+            let ix = instruction::write_chunk(
+                crate::ID,
+                account.address,
+                account.metadata_address,
+                buffer_account,
+                self.payer.pubkey(),
+                WriteChunkArgs {
+                    offset: (i * UPLOAD_CHUNK_SIZE) as u32,
+                    bytes: chunk.to_vec(),
+                },
+            );
+            self.send(&[ix], &[]).await?;
+        }
+
+        let mut ix = instruction::commit_upload(
+            crate::ID,
+            account.address,
+            account.metadata_address,
+            buffer_account,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            CommitUploadArgs {
+                merge,
+                expected_revision: None,
+            },
+        );
+        account.push_write_accounts(&mut ix);
+        self.send(&[ix], &[]).await
+    }
+
+    /// Permanently makes the JSON account immutable.
+    pub async fn freeze(&mut self, json_account: &Pubkey) -> Result<(), ClientError> {
+        let (json_metadata_account, _) = find_metadata_account(json_account);
+        let ix = instruction::freeze(
+            crate::ID,
+            *json_account,
+            json_metadata_account,
+            self.payer.pubkey(),
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    /// Chooses how the JSON data is serialized, re-encoding the existing document.
+    pub async fn set_encoding(
+        &mut self,
+        json_account: &Pubkey,
+        encoding: Encoding,
+    ) -> Result<(), ClientError> {
+        let (json_metadata_account, _) = find_metadata_account(json_account);
+        let ix = instruction::set_encoding(
+            crate::ID,
+            *json_account,
+            json_metadata_account,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            SetEncodingArgs { encoding },
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    /// Grants `roles` to `new_authority`, optionally limited to the given JSON pointer `paths`.
+    pub async fn add_authority(
+        &mut self,
+        json_account: &Pubkey,
+        new_authority: &Pubkey,
+        roles: u8,
+        paths: Vec<String>,
+    ) -> Result<(), ClientError> {
+        let (json_metadata_account, _) = find_metadata_account(json_account);
+        let ix = instruction::add_authority(
+            crate::ID,
+            *json_account,
+            json_metadata_account,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            AddAuthorityArgs {
+                new_authority: *new_authority,
+                roles,
+                paths,
+            },
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    /// Revokes every role held by `authority`.
+    pub async fn remove_authority(
+        &mut self,
+        json_account: &Pubkey,
+        authority: &Pubkey,
+    ) -> Result<(), ClientError> {
+        let (json_metadata_account, _) = find_metadata_account(json_account);
+        let ix = instruction::remove_authority(
+            crate::ID,
+            *json_account,
+            json_metadata_account,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+            RemoveAuthorityArgs {
+                authority: *authority,
+            },
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    /// Removes every authority and permanently makes the JSON account immutable.
+    pub async fn renounce_all(&mut self, json_account: &Pubkey) -> Result<(), ClientError> {
+        let (json_metadata_account, _) = find_metadata_account(json_account);
+        let ix = instruction::renounce_all(
+            crate::ID,
+            *json_account,
+            json_metadata_account,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    /// Rewrites a legacy JSON metadata account in the current layout.
+    pub async fn migrate(&mut self, json_account: &Pubkey) -> Result<(), ClientError> {
+        let (json_metadata_account, _) = find_metadata_account(json_account);
+        let ix = instruction::migrate(
+            crate::ID,
+            *json_account,
+            json_metadata_account,
+            self.payer.pubkey(),
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    /// Closes the JSON account and its metadata, returning the rent to the payer.
+    pub async fn close(&mut self, json_account: &Pubkey) -> Result<(), ClientError> {
+        let account = self.fetch(json_account).await?;
+        let mut ix = instruction::close(
+            crate::ID,
+            account.address,
+            account.metadata_address,
+            self.payer.pubkey(),
+            self.payer.pubkey(),
+        );
+        if let Some(schema) = account.metadata.schema {
+            ix.accounts.push(AccountMeta::new(schema, false));
+        }
+        self.send(&[ix], &[]).await
+    }
+
+    async fn fetch_data(&mut self, address: &Pubkey) -> Result<Vec<u8>, ClientError> {
+        self.connection
+            .get_account_data(address)
+            .await?
+            .ok_or(ClientError::AccountNotFound(*address))
+    }
+
+    async fn send(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), ClientError> {
+        let blockhash = self.connection.get_latest_blockhash().await?;
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        self.connection.send_transaction(transaction).await
+    }
+}
@@ -0,0 +1,503 @@
+//! Wrappers for calling the program from other on-chain programs.
+//!
+//! Depend on this crate with the `no-entrypoint` feature to use them. Each wrapper signs with
+//! `signers_seeds`, so the authority may be a PDA of the calling program as long as it is listed
+//! in `JsonMetadata.authorities`.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+};
+
+use crate::instruction::{
+    self, AddAuthorityArgs, AppendValueArgs, BeginUploadArgs, CancelProposalArgs, CommitUploadArgs,
+    DeleteAtPointerArgs, PatchValueArgs, ProposeAuthorityArgs, RemoveAuthorityArgs,
+    SetAtPointerArgs, SetThresholdArgs, SetValueArgs, VerifyHashArgs, WriteChunkArgs,
+};
+
+/// The accounts of every instruction that changes the JSON data.
+pub struct WriteAccounts<'a, 'b> {
+    /// The JSON program itself.
+    pub json_program: &'a AccountInfo<'b>,
+    pub json_account: &'a AccountInfo<'b>,
+    pub json_metadata_account: &'a AccountInfo<'b>,
+    /// An authority allowed to make the change, usually a PDA signing through `signers_seeds`.
+    pub authority: &'a AccountInfo<'b>,
+    pub payer: &'a AccountInfo<'b>,
+    pub rent_destination: &'a AccountInfo<'b>,
+    pub system_program: &'a AccountInfo<'b>,
+    /// The attached schema account, required when the JSON account has a schema.
+    pub schema_account: Option<&'a AccountInfo<'b>>,
+    /// Authorities co-signing to meet the threshold.
+    pub co_signers: &'a [AccountInfo<'b>],
+}
+
+impl<'a, 'b> WriteAccounts<'a, 'b> {
+    /// Appends the optional accounts to `ix` and invokes it.
+    fn invoke(&self, ix: Instruction, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        let account_infos = vec![
+            self.json_account.clone(),
+            self.json_metadata_account.clone(),
+            self.authority.clone(),
+            self.payer.clone(),
+            self.rent_destination.clone(),
+            self.system_program.clone(),
+        ];
+        invoke_with_optional_accounts(
+            ix,
+            account_infos,
+            self.json_program,
+            self.schema_account,
+            self.co_signers,
+            signers_seeds,
+        )
+    }
+}
+
+/// The accounts of `Close`.
+pub struct CloseAccounts<'a, 'b> {
+    /// The JSON program itself.
+    pub json_program: &'a AccountInfo<'b>,
+    pub json_account: &'a AccountInfo<'b>,
+    pub json_metadata_account: &'a AccountInfo<'b>,
+    /// An admin of the JSON account, usually a PDA signing through `signers_seeds`.
+    pub authority: &'a AccountInfo<'b>,
+    pub rent_destination: &'a AccountInfo<'b>,
+    pub system_program: &'a AccountInfo<'b>,
+    /// The account at the upload buffer's address, closed too if an upload is in progress.
+    pub buffer_account: &'a AccountInfo<'b>,
+    /// The attached schema account, required when the JSON account has a schema.
+    pub schema_account: Option<&'a AccountInfo<'b>>,
+    /// Authorities co-signing to meet the threshold.
+    pub co_signers: &'a [AccountInfo<'b>],
+}
+
+/// The accounts of every instruction that changes the authorities or the threshold.
+pub struct AuthorityAccounts<'a, 'b> {
+    /// The JSON program itself.
+    pub json_program: &'a AccountInfo<'b>,
+    pub json_account: &'a AccountInfo<'b>,
+    pub json_metadata_account: &'a AccountInfo<'b>,
+    /// The admin making the change, or the proposed authority for `accept_authority`.
+    pub authority: &'a AccountInfo<'b>,
+    pub payer: &'a AccountInfo<'b>,
+    pub system_program: &'a AccountInfo<'b>,
+    /// Authorities co-signing to meet the threshold.
+    pub co_signers: &'a [AccountInfo<'b>],
+}
+
+impl<'a, 'b> AuthorityAccounts<'a, 'b> {
+    /// Appends the co-signers to `ix` and invokes it.
+    fn invoke(&self, ix: Instruction, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        let account_infos = vec![
+            self.json_account.clone(),
+            self.json_metadata_account.clone(),
+            self.authority.clone(),
+            self.payer.clone(),
+            self.system_program.clone(),
+        ];
+        invoke_with_optional_accounts(
+            ix,
+            account_infos,
+            self.json_program,
+            None,
+            self.co_signers,
+            signers_seeds,
+        )
+    }
+}
+
+/// The accounts of the chunked upload instructions.
+pub struct UploadAccounts<'a, 'b> {
+    /// The JSON program itself.
+    pub json_program: &'a AccountInfo<'b>,
+    pub json_account: &'a AccountInfo<'b>,
+    pub json_metadata_account: &'a AccountInfo<'b>,
+    /// The upload buffer at the address derived from the JSON account.
+    pub buffer_account: &'a AccountInfo<'b>,
+    /// The writer that began the upload, usually a PDA signing through `signers_seeds`.
+    pub authority: &'a AccountInfo<'b>,
+    pub payer: &'a AccountInfo<'b>,
+    pub rent_destination: &'a AccountInfo<'b>,
+    pub system_program: &'a AccountInfo<'b>,
+    /// The attached schema account, required by `commit_upload` when the JSON account has a
+    /// schema.
+    pub schema_account: Option<&'a AccountInfo<'b>>,
+    /// Authorities co-signing to meet the threshold.
+    pub co_signers: &'a [AccountInfo<'b>],
+}
+
+impl<'a, 'b> UploadAccounts<'a, 'b> {
+    /// Appends the co-signers, and the schema account if `with_schema` is set, to `ix` and
+    /// invokes it.
+    fn invoke(
+        &self,
+        ix: Instruction,
+        with_schema: bool,
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let account_infos = vec![
+            self.json_account.clone(),
+            self.json_metadata_account.clone(),
+            self.buffer_account.clone(),
+            self.authority.clone(),
+            self.payer.clone(),
+            self.rent_destination.clone(),
+            self.system_program.clone(),
+        ];
+        invoke_with_optional_accounts(
+            ix,
+            account_infos,
+            self.json_program,
+            self.schema_account.filter(|_| with_schema),
+            self.co_signers,
+            signers_seeds,
+        )
+    }
+}
+
+/// Appends the schema account and co-signers to `ix`, which the program expects after the fixed
+/// accounts, and invokes it.
+fn invoke_with_optional_accounts<'b>(
+    mut ix: Instruction,
+    mut account_infos: Vec<AccountInfo<'b>>,
+    json_program: &AccountInfo<'b>,
+    schema_account: Option<&AccountInfo<'b>>,
+    co_signers: &[AccountInfo<'b>],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    if let Some(schema_account) = schema_account {
+        ix.accounts
+            .push(AccountMeta::new_readonly(*schema_account.key, false));
+        account_infos.push(schema_account.clone());
+    }
+
+    for co_signer in co_signers {
+        ix.accounts
+            .push(AccountMeta::new_readonly(*co_signer.key, true));
+        account_infos.push(co_signer.clone());
+    }
+
+    account_infos.push(json_program.clone());
+    invoke_signed(&ix, &account_infos, signers_seeds)
+}
+
+/// Invokes `SetValue`, merging `args.value` into the JSON data.
+pub fn set_value(
+    accounts: WriteAccounts,
+    args: SetValueArgs,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = instruction::set_value(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.authority.key,
+        *accounts.payer.key,
+        *accounts.rent_destination.key,
+        args,
+    );
+    accounts.invoke(ix, signers_seeds)
+}
+
+/// Invokes `AppendValue`, appending `args.value` to the strings and arrays of the JSON data.
+pub fn append_value(
+    accounts: WriteAccounts,
+    args: AppendValueArgs,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = instruction::append_value(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.authority.key,
+        *accounts.payer.key,
+        *accounts.rent_destination.key,
+        args,
+    );
+    accounts.invoke(ix, signers_seeds)
+}
+
+/// Invokes `PatchValue`, applying an RFC 6902 JSON Patch document to the JSON data.
+pub fn patch_value(
+    accounts: WriteAccounts,
+    args: PatchValueArgs,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = instruction::patch_value(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.authority.key,
+        *accounts.payer.key,
+        *accounts.rent_destination.key,
+        args,
+    );
+    accounts.invoke(ix, signers_seeds)
+}
+
+/// Invokes `SetAtPointer`, writing a value at a JSON Pointer.
+pub fn set_at_pointer(
+    accounts: WriteAccounts,
+    args: SetAtPointerArgs,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = instruction::set_at_pointer(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.authority.key,
+        *accounts.payer.key,
+        *accounts.rent_destination.key,
+        args,
+    );
+    accounts.invoke(ix, signers_seeds)
+}
+
+/// Invokes `DeleteAtPointer`, removing the value at a JSON Pointer.
+pub fn delete_at_pointer(
+    accounts: WriteAccounts,
+    args: DeleteAtPointerArgs,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = instruction::delete_at_pointer(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.authority.key,
+        *accounts.payer.key,
+        *accounts.rent_destination.key,
+        args,
+    );
+    accounts.invoke(ix, signers_seeds)
+}
+
+/// Invokes `Close`, closing the JSON account, its metadata and any attached schema or pending
+/// upload.
+pub fn close(accounts: CloseAccounts, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+    // The builder already lists the buffer account, so only the schema and co-signers are added.
+    let ix = instruction::close(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.authority.key,
+        *accounts.rent_destination.key,
+    );
+    let account_infos = vec![
+        accounts.json_account.clone(),
+        accounts.json_metadata_account.clone(),
+        accounts.authority.clone(),
+        accounts.rent_destination.clone(),
+        accounts.system_program.clone(),
+        accounts.buffer_account.clone(),
+    ];
+    invoke_with_optional_accounts(
+        ix,
+        account_infos,
+        accounts.json_program,
+        accounts.schema_account,
+        accounts.co_signers,
+        signers_seeds,
+    )
+}
+
+/// Invokes `AddAuthority`, granting roles to an authority.
+pub fn add_authority(
+    accounts: AuthorityAccounts,
+    args: AddAuthorityArgs,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = instruction::add_authority(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.authority.key,
+        *accounts.payer.key,
+        args,
+    );
+    accounts.invoke(ix, signers_seeds)
+}
+
+/// Invokes `RemoveAuthority`, revoking an authority.
+pub fn remove_authority(
+    accounts: AuthorityAccounts,
+    args: RemoveAuthorityArgs,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = instruction::remove_authority(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.authority.key,
+        *accounts.payer.key,
+        args,
+    );
+    accounts.invoke(ix, signers_seeds)
+}
+
+/// Invokes `ProposeAuthority`, proposing roles that the new authority must accept.
+pub fn propose_authority(
+    accounts: AuthorityAccounts,
+    args: ProposeAuthorityArgs,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = instruction::propose_authority(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.authority.key,
+        *accounts.payer.key,
+        args,
+    );
+    accounts.invoke(ix, signers_seeds)
+}
+
+/// Invokes `AcceptAuthority`, accepting the roles proposed to `accounts.authority`.
+pub fn accept_authority(accounts: AuthorityAccounts, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+    let ix = instruction::accept_authority(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.authority.key,
+        *accounts.payer.key,
+    );
+    accounts.invoke(ix, signers_seeds)
+}
+
+/// Invokes `CancelProposal`, withdrawing a pending proposal.
+pub fn cancel_proposal(
+    accounts: AuthorityAccounts,
+    args: CancelProposalArgs,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = instruction::cancel_proposal(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.authority.key,
+        *accounts.payer.key,
+        args,
+    );
+    accounts.invoke(ix, signers_seeds)
+}
+
+/// Invokes `SetThreshold`, changing how many admins must approve a change. The payer and system
+/// program aren't used.
+pub fn set_threshold(
+    accounts: AuthorityAccounts,
+    args: SetThresholdArgs,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = instruction::set_threshold(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.authority.key,
+        args,
+    );
+    accounts.invoke(ix, signers_seeds)
+}
+
+/// Invokes `RenounceAll`, removing every authority so the JSON account can never change again.
+pub fn renounce_all(accounts: AuthorityAccounts, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+    let ix = instruction::renounce_all(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.authority.key,
+        *accounts.payer.key,
+    );
+    accounts.invoke(ix, signers_seeds)
+}
+
+/// Invokes `BeginUpload`, creating the buffer for a document of `args.size` bytes.
+pub fn begin_upload(
+    accounts: UploadAccounts,
+    args: BeginUploadArgs,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = instruction::begin_upload(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.buffer_account.key,
+        *accounts.authority.key,
+        *accounts.payer.key,
+        args,
+    );
+    accounts.invoke(ix, false, signers_seeds)
+}
+
+/// Invokes `WriteChunk`, copying `args.bytes` into the upload buffer.
+pub fn write_chunk(
+    accounts: UploadAccounts,
+    args: WriteChunkArgs,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = instruction::write_chunk(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.buffer_account.key,
+        *accounts.authority.key,
+        args,
+    );
+    accounts.invoke(ix, false, signers_seeds)
+}
+
+/// Invokes `CommitUpload`, storing the uploaded document and closing the buffer.
+pub fn commit_upload(
+    accounts: UploadAccounts,
+    args: CommitUploadArgs,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = instruction::commit_upload(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.buffer_account.key,
+        *accounts.authority.key,
+        *accounts.payer.key,
+        *accounts.rent_destination.key,
+        args,
+    );
+    accounts.invoke(ix, true, signers_seeds)
+}
+
+/// Invokes `CancelUpload`, discarding the upload buffer.
+pub fn cancel_upload(accounts: UploadAccounts, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+    let ix = instruction::cancel_upload(
+        crate::ID,
+        *accounts.json_account.key,
+        *accounts.json_metadata_account.key,
+        *accounts.buffer_account.key,
+        *accounts.authority.key,
+        *accounts.rent_destination.key,
+    );
+    accounts.invoke(ix, false, signers_seeds)
+}
+
+/// Invokes `VerifyHash`, failing unless the JSON data has the expected hash.
+pub fn verify_hash<'a>(
+    json_program: &AccountInfo<'a>,
+    json_account: &AccountInfo<'a>,
+    json_metadata_account: &AccountInfo<'a>,
+    args: VerifyHashArgs,
+) -> ProgramResult {
+    let ix = instruction::verify_hash(
+        crate::ID,
+        *json_account.key,
+        *json_metadata_account.key,
+        args,
+    );
+    invoke(
+        &ix,
+        &[
+            json_account.clone(),
+            json_metadata_account.clone(),
+            json_program.clone(),
+        ],
+    )
+}
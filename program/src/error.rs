@@ -30,6 +30,90 @@ pub enum OnchainMetadataError {
 
     #[error("The payer does not have authority to perform this action.")]
     InvalidAuthority,
+
+    #[error("The JSON patch could not be applied.")]
+    PatchFailed,
+
+    #[error("The JSON pointer is invalid.")]
+    InvalidPointer,
+
+    #[error("The JSON pointer does not reference an existing location.")]
+    PointerNotFound,
+
+    #[error("The JSON account is immutable.")]
+    Immutable,
+
+    #[error("The key for the upload buffer account is invalid.")]
+    BufferDerivedKeyInvalid,
+
+    #[error("The chunk does not fit in the upload buffer.")]
+    ChunkOutOfBounds,
+
+    #[error("The key for the schema account is invalid.")]
+    SchemaDerivedKeyInvalid,
+
+    #[error("The schema is invalid or uses unsupported keywords.")]
+    InvalidSchema,
+
+    #[error("The JSON data does not conform to the attached schema.")]
+    SchemaViolation,
+
+    #[error("The key for the JSON account is invalid.")]
+    JsonDerivedKeyInvalid,
+
+    #[error("The JSON metadata account uses a legacy layout and must be migrated first.")]
+    MetadataNeedsMigration,
+
+    #[error("The roles are empty or contain unknown bits.")]
+    InvalidRoles,
+
+    #[error("The authority is not permitted to change this path.")]
+    PathNotPermitted,
+
+    #[error("The last admin authority can only be given up with RenounceAll.")]
+    LastAuthority,
+
+    #[error("There is no pending proposal for this authority.")]
+    ProposalNotFound,
+
+    #[error("Fewer authorities signed than the threshold requires.")]
+    NotEnoughSigners,
+
+    #[error("The threshold must be at least one and reachable by the admins and writers.")]
+    InvalidThreshold,
+
+    #[error("The JSON data has changed since the expected revision.")]
+    RevisionMismatch,
+
+    #[error("The JSON data does not match the expected hash.")]
+    HashMismatch,
+
+    #[error("The previous instruction must verify a single ed25519 signature over its own data.")]
+    InvalidSignatureInstruction,
+
+    #[error("The signed message does not match the current JSON data.")]
+    AttestationMismatch,
+
+    #[error("The attestation account does not match the derived address.")]
+    AttestationDerivedKeyInvalid,
+
+    #[error("The value at the JSON Pointer does not have the requested type.")]
+    TypeMismatch,
+
+    #[error("Only the authority that began the upload may write, commit or cancel it.")]
+    InvalidUploader,
+
+    #[error("The seed must be between 1 and 32 bytes long.")]
+    InvalidSeed,
+
+    #[error("The mint account does not match the arguments or is not owned by a token program.")]
+    InvalidMint,
+
+    #[error("Admins can only be added by proposing them and having them accept.")]
+    AdminRequiresProposal,
+
+    #[error("The JSON data is not stored as JSON text, so it can't be read in place.")]
+    UnsupportedEncoding,
 }
 
 impl PrintProgramError for OnchainMetadataError {
@@ -0,0 +1,176 @@
+//! Structured events that let indexers follow JSON accounts without re-fetching them.
+//!
+//! Each event is logged with `sol_log_data` as two fields: [`EVENT_TAG`] followed by the Borsh
+//! encoding of a [`JsonEvent`]. RPC nodes report them as `Program data: <base64> <base64>` log
+//! lines, which [`JsonEvent::from_log`] decodes.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde_json::Value;
+use solana_program::{entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey};
+
+use crate::state::{AuthorityEntry, JsonMetadata};
+
+/// The first field of every event, which tells them apart from data logged by other programs.
+pub const EVENT_TAG: &[u8] = b"JSON";
+
+/// The prefix RPC nodes give log lines written by `sol_log_data`.
+const LOG_DATA_PREFIX: &str = "Program data: ";
+
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum JsonEvent {
+    /// A JSON account was created holding `null`, with `authority` as its only authority.
+    Initialize {
+        json_account: Pubkey,
+        authority: Pubkey,
+    },
+    /// The JSON data was written.
+    ValueChanged {
+        json_account: Pubkey,
+        /// The revision of the JSON data after the write.
+        revision: u64,
+        /// The hash of the JSON data after the write.
+        hash: [u8; 32],
+        /// JSON Pointers to the locations the write targeted. `""` means the whole document was
+        /// replaced and no paths means only its encoding changed.
+        changed_paths: Vec<String>,
+    },
+    /// The authorities or threshold of a JSON account changed.
+    AuthoritiesChanged {
+        json_account: Pubkey,
+        /// Every authority after the change.
+        authorities: Vec<AuthorityEntry>,
+        threshold: u8,
+    },
+    /// A JSON account was closed.
+    Close { json_account: Pubkey },
+    /// A JSON account was frozen, so neither its data nor its metadata can change again.
+    Freeze { json_account: Pubkey },
+    /// An authority was proposed or a proposal was cancelled.
+    ProposalsChanged {
+        json_account: Pubkey,
+        /// Every pending proposal after the change.
+        pending_authorities: Vec<AuthorityEntry>,
+    },
+    /// A schema was attached to a JSON account, or detached when `schema` is `None`.
+    SchemaChanged {
+        json_account: Pubkey,
+        schema: Option<Pubkey>,
+    },
+    /// The metadata of a JSON account was migrated from a legacy layout.
+    Migrate {
+        json_account: Pubkey,
+        /// The revision after the migration.
+        revision: u64,
+        /// The hash of the JSON data, which legacy metadata didn't record.
+        hash: [u8; 32],
+    },
+}
+
+impl JsonEvent {
+    /// Builds a `ValueChanged` event for a write that `json_metadata` has already recorded.
+    pub fn value_changed(
+        json_account: &Pubkey,
+        json_metadata: &JsonMetadata,
+        changed_paths: Vec<String>,
+    ) -> Self {
+        JsonEvent::ValueChanged {
+            json_account: *json_account,
+            revision: json_metadata.revision,
+            hash: json_metadata.hash,
+            changed_paths,
+        }
+    }
+
+    /// Builds an `AuthoritiesChanged` event from the updated `json_metadata`.
+    pub fn authorities_changed(json_account: &Pubkey, json_metadata: &JsonMetadata) -> Self {
+        JsonEvent::AuthoritiesChanged {
+            json_account: *json_account,
+            authorities: json_metadata.authorities.clone(),
+            threshold: json_metadata.threshold,
+        }
+    }
+
+    /// Builds a `ProposalsChanged` event from the updated `json_metadata`.
+    pub fn proposals_changed(json_account: &Pubkey, json_metadata: &JsonMetadata) -> Self {
+        JsonEvent::ProposalsChanged {
+            json_account: *json_account,
+            pending_authorities: json_metadata.pending_authorities.clone(),
+        }
+    }
+
+    /// Logs the event.
+    pub fn emit(&self) -> ProgramResult {
+        sol_log_data(&[EVENT_TAG, &self.try_to_vec()?]);
+
+        Ok(())
+    }
+
+    /// Decodes the fields of a `sol_log_data` call, returning `None` if they aren't an event.
+    pub fn decode(fields: &[&[u8]]) -> Option<Self> {
+        match fields {
+            [tag, data] if *tag == EVENT_TAG => Self::try_from_slice(data).ok(),
+            _ => None,
+        }
+    }
+
+    /// Decodes a program log line, returning `None` if it isn't an event.
+    ///
+    /// Only lines logged while this program is executing are events, which callers reading whole
+    /// transaction logs should check with the surrounding `invoke` and `success` lines.
+    pub fn from_log(line: &str) -> Option<Self> {
+        let fields = line
+            .strip_prefix(LOG_DATA_PREFIX)?
+            .split(' ')
+            .map(base64::decode)
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+        let fields: Vec<&[u8]> = fields.iter().map(Vec::as_slice).collect();
+        Self::decode(&fields)
+    }
+}
+
+/// Returns the locations a JSON Merge Patch style `value` writes to, which are its leaves.
+///
+/// Non-empty objects are descended into because `SetValue` and `AppendValue` merge them member by
+/// member. Anything else, including the root when `value` isn't an object, is written whole.
+pub fn merge_paths(value: &Value) -> Vec<String> {
+    let mut paths = Vec::new();
+    collect_merge_paths(value, &mut String::new(), &mut paths);
+    paths
+}
+
+fn collect_merge_paths(value: &Value, path: &mut String, paths: &mut Vec<String>) {
+    match value {
+        Value::Object(members) if !members.is_empty() => {
+            for (key, value) in members {
+                let len = path.len();
+                path.push('/');
+                path.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                collect_merge_paths(value, path, paths);
+                path.truncate(len);
+            }
+        }
+        _ => paths.push(path.clone()),
+    }
+}
+
+/// Returns the locations an RFC 6902 JSON Patch document writes to, leaving out `test`
+/// operations. Moves change both their source and destination.
+pub fn patch_paths(patch: &Value) -> Vec<String> {
+    let mut paths = Vec::new();
+    for operation in patch.as_array().into_iter().flatten() {
+        let op = operation.get("op").and_then(Value::as_str);
+        if op == Some("test") {
+            continue;
+        }
+        if op == Some("move") {
+            if let Some(from) = operation.get("from").and_then(Value::as_str) {
+                paths.push(from.to_string());
+            }
+        }
+        if let Some(path) = operation.get("path").and_then(Value::as_str) {
+            paths.push(path.to_string());
+        }
+    }
+    paths
+}
@@ -3,13 +3,20 @@ use shank::ShankInstruction;
 use solana_program::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
-    system_program,
+    system_program, sysvar,
+};
+
+use crate::{
+    pda::{find_attestation_account, find_buffer_account},
+    state::Encoding,
 };
 
 #[repr(C)]
 #[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct SetValueArgs {
     pub value: String,
+    /// Fail with `RevisionMismatch` unless the JSON data is still at this revision.
+    pub expected_revision: Option<u64>,
 }
 
 #[repr(C)]
@@ -17,12 +24,19 @@ pub struct SetValueArgs {
 #[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct AppendValueArgs {
     pub value: String,
+    /// Fail with `RevisionMismatch` unless the JSON data is still at this revision.
+    pub expected_revision: Option<u64>,
 }
 
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub struct AddAuthorityArgs {
     pub new_authority: Pubkey,
+    /// A bitmask of the `ROLE_*` constants in `state` to grant to `new_authority`. `ROLE_ADMIN`
+    /// can only be kept by existing admins; new admins go through `ProposeAuthority`.
+    pub roles: u8,
+    /// JSON Pointer prefixes `new_authority` may change. Empty grants the whole document.
+    pub paths: Vec<String>,
 }
 
 #[repr(C)]
@@ -31,52 +45,348 @@ pub struct RemoveAuthorityArgs {
     pub authority: Pubkey,
 }
 
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct PatchValueArgs {
+    /// An RFC 6902 JSON Patch document.
+    pub patch: String,
+    /// Fail with `RevisionMismatch` unless the JSON data is still at this revision.
+    pub expected_revision: Option<u64>,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetAtPointerArgs {
+    /// An RFC 6901 JSON Pointer to the location to write.
+    pub pointer: String,
+    /// The JSON value to write, which may be an explicit `null`.
+    pub value: String,
+    /// Fail with `RevisionMismatch` unless the JSON data is still at this revision.
+    pub expected_revision: Option<u64>,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct DeleteAtPointerArgs {
+    /// An RFC 6901 JSON Pointer to the location to delete.
+    pub pointer: String,
+    /// Fail with `RevisionMismatch` unless the JSON data is still at this revision.
+    pub expected_revision: Option<u64>,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct BeginUploadArgs {
+    /// The exact length in bytes of the document being uploaded.
+    pub size: u32,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct WriteChunkArgs {
+    /// Where the bytes go in the document being uploaded.
+    pub offset: u32,
+    pub bytes: Vec<u8>,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct CommitUploadArgs {
+    /// Merge the uploaded document into the existing data instead of replacing it.
+    pub merge: bool,
+    /// Fail with `RevisionMismatch` unless the JSON data is still at this revision.
+    pub expected_revision: Option<u64>,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetSchemaArgs {
+    /// A JSON Schema using the supported subset of keywords.
+    pub schema: String,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct InitializeWithSeedArgs {
+    /// 1 to 32 bytes distinguishing this JSON account from others of the same owner.
+    pub seed: String,
+    /// An optional mint the JSON account is derived from, which must be owned by a token program.
+    pub mint: Option<Pubkey>,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct ProposeAuthorityArgs {
+    pub new_authority: Pubkey,
+    /// A bitmask of the `ROLE_*` constants in `state` to grant once accepted.
+    pub roles: u8,
+    /// JSON Pointer prefixes `new_authority` may change. Empty grants the whole document.
+    pub paths: Vec<String>,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct CancelProposalArgs {
+    pub authority: Pubkey,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetThresholdArgs {
+    /// How many authorities must sign each authority-checked instruction.
+    pub threshold: u8,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct VerifyHashArgs {
+    /// The SHA-256 hash the JSON data must have in RFC 8785 canonical form. The hash doesn't
+    /// depend on the account's encoding, so it can be computed from the document alone.
+    pub expected: [u8; 32],
+}
+
+#[repr(C)]
+#[derive(PartialEq, Eq, Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetEncodingArgs {
+    /// How the JSON data is serialized from now on.
+    pub encoding: Encoding,
+}
+
+/// Instructions that check an authority accept further authorities as signers after their listed
+/// accounts, which count towards `JsonMetadata::threshold`.
 #[derive(Debug, Clone, ShankInstruction, BorshSerialize, BorshDeserialize)]
 #[rustfmt::skip]
 pub enum OnchainMetadataInstructions {
     /// Description of this instruction
     #[account(0, writable, signer, name="json_account", desc = "The account to store the metadata in.")]
     #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
-    #[account(2, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
-    #[account(3, name="system_program", desc = "System program")]
+    #[account(2, name="authority", desc="The first authority of the JSON account, which needn't sign.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, name="system_program", desc = "System program")]
     Initialize,
 
-    /// Description of this instruction
+    /// Close the JSON account and its metadata, sending their rent to the rent destination.
+    /// Frozen accounts can still be closed by their admins, so their rent isn't stranded.
     #[account(0, writable, name="json_account", desc = "The account to store the metadata in.")]
     #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
-    #[account(2, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
-    #[account(3, name="system_program", desc = "System program")]
+    #[account(2, signer, name="authority", desc="The authority approving the change.")]
+    #[account(3, writable, name="rent_destination", desc="The account that will receive the rent.")]
+    #[account(4, name="system_program", desc = "System program")]
+    #[account(5, writable, name="buffer_account", desc = "The upload buffer, closed if an upload is in progress.")]
+    #[account(6, optional, writable, name="schema_account", desc = "The attached schema account, required when a schema is set.")]
     Close,
 
     /// Description of this instruction
     #[account(0, writable, name="json_account", desc = "The account to store the metadata in.")]
     #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
-    #[account(2, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
-    #[account(3, name="system_program", desc = "System program")]
+    #[account(2, signer, name="authority", desc="The authority approving the change.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, writable, name="rent_destination", desc="The account that will receive any rent the document no longer needs.")]
+    #[account(5, name="system_program", desc = "System program")]
+    #[account(6, optional, name="schema_account", desc = "The attached schema account, required when a schema is set.")]
     SetValue(SetValueArgs),
 
     /// Description of this instruction
     #[account(0, writable, name="json_account", desc = "The account to store the metadata in.")]
     #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
-    #[account(2, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
-    #[account(3, name="system_program", desc = "System program")]
+    #[account(2, signer, name="authority", desc="The authority approving the change.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, writable, name="rent_destination", desc="The account that will receive any rent the document no longer needs.")]
+    #[account(5, name="system_program", desc = "System program")]
+    #[account(6, optional, name="schema_account", desc = "The attached schema account, required when a schema is set.")]
     AppendValue(AppendValueArgs),
 
-    #[account(0, writable, name="json_metadata_account", desc = "The account to store the metadata's metadata in.")]
-    #[account(1, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
-    #[account(2, name="system_program", desc = "System program")]
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, signer, name="authority", desc="The authority approving the change.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, name="system_program", desc = "System program")]
     AddAuthority(AddAuthorityArgs),
 
-    #[account(0, writable, name="json_metadata_account", desc = "The account to store the metadata's metadata in.")]
-    #[account(1, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
-    #[account(2, name="system_program", desc = "System program")]
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, signer, name="authority", desc="The authority approving the change.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, name="system_program", desc = "System program")]
     RemoveAuthority(RemoveAuthorityArgs),
+
+    /// Atomically apply an RFC 6902 JSON Patch to the JSON data.
+    #[account(0, writable, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, signer, name="authority", desc="The authority approving the change.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, writable, name="rent_destination", desc="The account that will receive any rent the document no longer needs.")]
+    #[account(5, name="system_program", desc = "System program")]
+    #[account(6, optional, name="schema_account", desc = "The attached schema account, required when a schema is set.")]
+    PatchValue(PatchValueArgs),
+
+    /// Write a single value at a JSON Pointer, creating or replacing it.
+    #[account(0, writable, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, signer, name="authority", desc="The authority approving the change.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, writable, name="rent_destination", desc="The account that will receive any rent the document no longer needs.")]
+    #[account(5, name="system_program", desc = "System program")]
+    #[account(6, optional, name="schema_account", desc = "The attached schema account, required when a schema is set.")]
+    SetAtPointer(SetAtPointerArgs),
+
+    /// Remove the value at a JSON Pointer.
+    #[account(0, writable, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, signer, name="authority", desc="The authority approving the change.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, writable, name="rent_destination", desc="The account that will receive any rent the document no longer needs.")]
+    #[account(5, name="system_program", desc = "System program")]
+    #[account(6, optional, name="schema_account", desc = "The attached schema account, required when a schema is set.")]
+    DeleteAtPointer(DeleteAtPointerArgs),
+
+    /// Permanently make the JSON account and its metadata immutable. Admins can still close it.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, signer, name="authority", desc="The authority approving the change.")]
+    Freeze,
+
+    /// Create a buffer account to upload a document larger than a single transaction.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, writable, name="buffer_account", desc = "The buffer to stage the upload in.")]
+    #[account(3, signer, name="authority", desc="The authority approving the change.")]
+    #[account(4, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(5, name="system_program", desc = "System program")]
+    BeginUpload(BeginUploadArgs),
+
+    /// Write raw bytes into the upload buffer at an offset.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, writable, name="buffer_account", desc = "The buffer to stage the upload in.")]
+    #[account(3, signer, name="authority", desc="The authority approving the change.")]
+    WriteChunk(WriteChunkArgs),
+
+    /// Validate the uploaded document and write it to the JSON account, closing the buffer.
+    #[account(0, writable, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, writable, name="buffer_account", desc = "The buffer to stage the upload in.")]
+    #[account(3, signer, name="authority", desc="The authority approving the change.")]
+    #[account(4, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(5, writable, name="rent_destination", desc="The account that will receive any rent the document no longer needs.")]
+    #[account(6, name="system_program", desc = "System program")]
+    #[account(7, optional, name="schema_account", desc = "The attached schema account, required when a schema is set.")]
+    CommitUpload(CommitUploadArgs),
+
+    /// Discard an upload in progress, closing the buffer.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, writable, name="buffer_account", desc = "The buffer to stage the upload in.")]
+    #[account(3, signer, name="authority", desc="The authority approving the change.")]
+    #[account(4, writable, name="rent_destination", desc="The account that will receive the buffer's rent.")]
+    CancelUpload,
+
+    /// Attach or replace the JSON Schema that writes must conform to.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, writable, name="schema_account", desc = "The account to store the schema in.")]
+    #[account(3, signer, name="authority", desc="The authority approving the change.")]
+    #[account(4, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(5, name="system_program", desc = "System program")]
+    SetSchema(SetSchemaArgs),
+
+    /// Detach the JSON Schema and close its account.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, writable, name="schema_account", desc = "The account the schema is stored in.")]
+    #[account(3, signer, name="authority", desc="The authority approving the change.")]
+    #[account(4, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(5, writable, name="rent_destination", desc="The account that will receive the schema's rent.")]
+    #[account(6, name="system_program", desc = "System program")]
+    RemoveSchema,
+
+    /// Initialize a JSON account at an address derived from its owner, a seed and an optional mint.
+    #[account(0, writable, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, name="owner", desc="The wallet the JSON account is derived from, which becomes its first authority.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, name="system_program", desc = "System program")]
+    #[account(5, optional, name="mint", desc = "The mint the JSON account is derived from, required when a mint is given.")]
+    InitializeWithSeed(InitializeWithSeedArgs),
+
+    /// Rewrite a legacy JSON metadata account in the current layout.
+    /// Legacy authorities keep every role.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(3, name="system_program", desc = "System program")]
+    Migrate,
+
+    /// Remove every authority and pending proposal and make the JSON account immutable. With no
+    /// admins left, the account can never be closed and its rent is locked in for good.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, signer, name="authority", desc="The authority approving the change.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, name="system_program", desc = "System program")]
+    RenounceAll,
+
+    /// Propose a new authority, which only takes effect once it signs AcceptAuthority.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, signer, name="authority", desc="The authority approving the change.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, name="system_program", desc = "System program")]
+    ProposeAuthority(ProposeAuthorityArgs),
+
+    /// Accept a pending proposal, becoming an authority with the proposed roles and paths.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, signer, name="authority", desc="The proposed authority accepting the proposal.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, name="system_program", desc = "System program")]
+    AcceptAuthority,
+
+    /// Withdraw a pending proposal, either by an admin or by the proposed authority declining it.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, signer, name="authority", desc="An admin authority or the proposed authority.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, name="system_program", desc = "System program")]
+    CancelProposal(CancelProposalArgs),
+
+    /// Set how many authorities must sign each authority-checked instruction.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, signer, name="authority", desc="The authority approving the change.")]
+    SetThreshold(SetThresholdArgs),
+
+    /// Fail unless the JSON data has the expected hash, so other programs can check its contents.
+    /// The hash is taken over the RFC 8785 canonical form of the document, whatever its encoding.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    VerifyHash(VerifyHashArgs),
+
+    /// Choose how the JSON data is serialized, re-encoding the existing document.
+    #[account(0, writable, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, writable, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, signer, name="authority", desc="The authority approving the change.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, writable, name="rent_destination", desc="The account that will receive any rent the document no longer needs.")]
+    #[account(5, name="system_program", desc = "System program")]
+    SetEncoding(SetEncodingArgs),
+
+    /// Record an attester's ed25519 signature over the current revision and hash of the JSON data.
+    /// The previous instruction must verify that signature with the ed25519 program.
+    #[account(0, name="json_account", desc = "The account to store the metadata in.")]
+    #[account(1, name="json_metadata_account", desc = "The account to store the json account's metadata in.")]
+    #[account(2, writable, name="attestation_account", desc = "The account to record the attestation in.")]
+    #[account(3, writable, signer, name="payer", desc="The account that will pay for the transaction and rent.")]
+    #[account(4, name="system_program", desc = "System program")]
+    #[account(5, name="instructions_sysvar", desc = "Instructions sysvar")]
+    Attest,
 }
 
 pub fn initialize(
     _program_id: Pubkey,
     json_account: Pubkey,
     json_metadata_account: Pubkey,
+    authority: Pubkey,
     payer: Pubkey,
 ) -> Instruction {
     Instruction {
@@ -84,6 +394,7 @@ pub fn initialize(
         accounts: vec![
             AccountMeta::new(json_account, true),
             AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, false),
             AccountMeta::new(payer, true),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
@@ -97,15 +408,18 @@ pub fn close(
     _program_id: Pubkey,
     json_account: Pubkey,
     json_metadata_account: Pubkey,
-    payer: Pubkey,
+    authority: Pubkey,
+    rent_destination: Pubkey,
 ) -> Instruction {
     Instruction {
         program_id: crate::ID,
         accounts: vec![
             AccountMeta::new(json_account, false),
             AccountMeta::new(json_metadata_account, false),
-            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(rent_destination, false),
             AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(find_buffer_account(&json_account).0, false),
         ],
         data: OnchainMetadataInstructions::Close.try_to_vec().unwrap(),
     }
@@ -115,7 +429,9 @@ pub fn set_value(
     _program_id: Pubkey,
     json_account: Pubkey,
     json_metadata_account: Pubkey,
+    authority: Pubkey,
     payer: Pubkey,
+    rent_destination: Pubkey,
     args: SetValueArgs,
 ) -> Instruction {
     Instruction {
@@ -123,7 +439,9 @@ pub fn set_value(
         accounts: vec![
             AccountMeta::new(json_account, false),
             AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
             AccountMeta::new(payer, true),
+            AccountMeta::new(rent_destination, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
         data: OnchainMetadataInstructions::SetValue(args)
@@ -136,7 +454,9 @@ pub fn append_value(
     _program_id: Pubkey,
     json_account: Pubkey,
     json_metadata_account: Pubkey,
+    authority: Pubkey,
     payer: Pubkey,
+    rent_destination: Pubkey,
     args: AppendValueArgs,
 ) -> Instruction {
     Instruction {
@@ -144,7 +464,9 @@ pub fn append_value(
         accounts: vec![
             AccountMeta::new(json_account, false),
             AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
             AccountMeta::new(payer, true),
+            AccountMeta::new(rent_destination, false),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
         data: OnchainMetadataInstructions::AppendValue(args)
@@ -155,14 +477,18 @@ pub fn append_value(
 
 pub fn add_authority(
     _program_id: Pubkey,
+    json_account: Pubkey,
     json_metadata_account: Pubkey,
+    authority: Pubkey,
     payer: Pubkey,
     args: AddAuthorityArgs,
 ) -> Instruction {
     Instruction {
         program_id: crate::ID,
         accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
             AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
             AccountMeta::new(payer, true),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
@@ -174,14 +500,18 @@ pub fn add_authority(
 
 pub fn remove_authority(
     _program_id: Pubkey,
+    json_account: Pubkey,
     json_metadata_account: Pubkey,
+    authority: Pubkey,
     payer: Pubkey,
     args: RemoveAuthorityArgs,
 ) -> Instruction {
     Instruction {
         program_id: crate::ID,
         accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
             AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
             AccountMeta::new(payer, true),
             AccountMeta::new_readonly(system_program::ID, false),
         ],
@@ -190,3 +520,465 @@ pub fn remove_authority(
             .unwrap(),
     }
 }
+
+pub fn patch_value(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    rent_destination: Pubkey,
+    args: PatchValueArgs,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new(rent_destination, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OnchainMetadataInstructions::PatchValue(args)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_at_pointer(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    rent_destination: Pubkey,
+    args: SetAtPointerArgs,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new(rent_destination, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OnchainMetadataInstructions::SetAtPointer(args)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn delete_at_pointer(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    rent_destination: Pubkey,
+    args: DeleteAtPointerArgs,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new(rent_destination, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OnchainMetadataInstructions::DeleteAtPointer(args)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn freeze(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
+        ],
+        data: OnchainMetadataInstructions::Freeze.try_to_vec().unwrap(),
+    }
+}
+
+pub fn begin_upload(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    buffer_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    args: BeginUploadArgs,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new_readonly(json_metadata_account, false),
+            AccountMeta::new(buffer_account, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OnchainMetadataInstructions::BeginUpload(args)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn write_chunk(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    buffer_account: Pubkey,
+    authority: Pubkey,
+    args: WriteChunkArgs,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new_readonly(json_metadata_account, false),
+            AccountMeta::new(buffer_account, false),
+            AccountMeta::new_readonly(authority, true),
+        ],
+        data: OnchainMetadataInstructions::WriteChunk(args)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn commit_upload(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    buffer_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    rent_destination: Pubkey,
+    args: CommitUploadArgs,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new(buffer_account, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new(rent_destination, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OnchainMetadataInstructions::CommitUpload(args)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn cancel_upload(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    buffer_account: Pubkey,
+    authority: Pubkey,
+    rent_destination: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new_readonly(json_metadata_account, false),
+            AccountMeta::new(buffer_account, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(rent_destination, false),
+        ],
+        data: OnchainMetadataInstructions::CancelUpload
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_schema(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    schema_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    args: SetSchemaArgs,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new(schema_account, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OnchainMetadataInstructions::SetSchema(args)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn remove_schema(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    schema_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    rent_destination: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new(schema_account, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new(rent_destination, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OnchainMetadataInstructions::RemoveSchema
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn initialize_with_seed(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    owner: Pubkey,
+    payer: Pubkey,
+    args: InitializeWithSeedArgs,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(json_account, false),
+        AccountMeta::new(json_metadata_account, false),
+        AccountMeta::new_readonly(owner, false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    if let Some(mint) = args.mint {
+        accounts.push(AccountMeta::new_readonly(mint, false));
+    }
+
+    Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: OnchainMetadataInstructions::InitializeWithSeed(args)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn migrate(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OnchainMetadataInstructions::Migrate.try_to_vec().unwrap(),
+    }
+}
+
+pub fn renounce_all(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OnchainMetadataInstructions::RenounceAll
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn propose_authority(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    args: ProposeAuthorityArgs,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OnchainMetadataInstructions::ProposeAuthority(args)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn accept_authority(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OnchainMetadataInstructions::AcceptAuthority
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn cancel_proposal(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    args: CancelProposalArgs,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OnchainMetadataInstructions::CancelProposal(args)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_threshold(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+    args: SetThresholdArgs,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
+        ],
+        data: OnchainMetadataInstructions::SetThreshold(args)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn verify_hash(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    args: VerifyHashArgs,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new_readonly(json_metadata_account, false),
+        ],
+        data: OnchainMetadataInstructions::VerifyHash(args)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_encoding(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    rent_destination: Pubkey,
+    args: SetEncodingArgs,
+) -> Instruction {
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new(json_account, false),
+            AccountMeta::new(json_metadata_account, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new(rent_destination, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: OnchainMetadataInstructions::SetEncoding(args)
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn attest(
+    _program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    attester: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    let (attestation_account, _) = find_attestation_account(&json_account, &attester);
+    Instruction {
+        program_id: crate::ID,
+        accounts: vec![
+            AccountMeta::new_readonly(json_account, false),
+            AccountMeta::new_readonly(json_metadata_account, false),
+            AccountMeta::new(attestation_account, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        ],
+        data: OnchainMetadataInstructions::Attest.try_to_vec().unwrap(),
+    }
+}
@@ -1,9 +1,21 @@
+pub mod canonical;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod cpi;
+#[cfg(not(feature = "no-entrypoint"))]
 pub mod entrypoint;
 pub mod error;
+pub mod event;
 pub mod instruction;
+pub mod patch;
 pub mod pda;
+pub mod pointer;
 pub mod processor;
+pub mod reader;
+pub mod schema;
 pub mod state;
+pub mod typed;
+pub mod utils;
 
 pub use solana_program;
 
@@ -0,0 +1,95 @@
+use serde_json::{Number, Value};
+
+use crate::{error::OnchainMetadataError, pointer};
+
+/// Applies an RFC 6902 JSON Patch document to `doc`.
+///
+/// Operations are applied in order and the first failing operation aborts the whole patch with
+/// `PatchFailed`. `doc` may be partially modified when an error is returned, so callers must not
+/// persist it in that case.
+pub fn apply(doc: &mut Value, patch: &Value) -> Result<(), OnchainMetadataError> {
+    let operations = patch.as_array().ok_or(OnchainMetadataError::PatchFailed)?;
+    for operation in operations {
+        apply_operation(doc, operation).map_err(|_| OnchainMetadataError::PatchFailed)?;
+    }
+
+    Ok(())
+}
+
+fn apply_operation(doc: &mut Value, operation: &Value) -> Result<(), OnchainMetadataError> {
+    let op = member_str(operation, "op")?;
+    let path = pointer::parse(member_str(operation, "path")?)?;
+
+    match op {
+        "add" => pointer::add(doc, &path, member(operation, "value")?.clone()),
+        "remove" => pointer::remove(doc, &path).map(|_| ()),
+        "replace" => {
+            *pointer::get_mut(doc, &path)? = member(operation, "value")?.clone();
+            Ok(())
+        }
+        "move" => {
+            let from = pointer::parse(member_str(operation, "from")?)?;
+            // A location cannot be moved into one of its own children.
+            if from.len() < path.len() && path.starts_with(&from) {
+                return Err(OnchainMetadataError::PatchFailed);
+            }
+            let value = pointer::remove(doc, &from)?;
+            pointer::add(doc, &path, value)
+        }
+        "copy" => {
+            let from = pointer::parse(member_str(operation, "from")?)?;
+            let value = pointer::get_mut(doc, &from)?.clone();
+            pointer::add(doc, &path, value)
+        }
+        "test" => {
+            if json_eq(pointer::get_mut(doc, &path)?, member(operation, "value")?) {
+                Ok(())
+            } else {
+                Err(OnchainMetadataError::PatchFailed)
+            }
+        }
+        _ => Err(OnchainMetadataError::PatchFailed),
+    }
+}
+
+fn member<'a>(operation: &'a Value, name: &str) -> Result<&'a Value, OnchainMetadataError> {
+    operation.get(name).ok_or(OnchainMetadataError::PatchFailed)
+}
+
+fn member_str<'a>(operation: &'a Value, name: &str) -> Result<&'a str, OnchainMetadataError> {
+    member(operation, name)?
+        .as_str()
+        .ok_or(OnchainMetadataError::PatchFailed)
+}
+
+/// Compares two values the way the `test` operation requires, treating numbers as equal when
+/// they are numerically equal regardless of their integer or float representation.
+fn json_eq(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => number_eq(a, b),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| json_eq(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(k, v)| b.get(k).map_or(false, |other| json_eq(v, other)))
+        }
+        _ => a == b,
+    }
+}
+
+/// Compares integers exactly, since doubles can't tell apart those above 2^53, and falls back to
+/// doubles only when either side is a float.
+fn number_eq(a: &Number, b: &Number) -> bool {
+    match (integer(a), integer(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a.as_f64() == b.as_f64(),
+    }
+}
+
+fn integer(n: &Number) -> Option<i128> {
+    n.as_u64()
+        .map(i128::from)
+        .or_else(|| n.as_i64().map(i128::from))
+}
@@ -1,6 +1,10 @@
 use solana_program::pubkey::Pubkey;
 
 pub const PREFIX: &str = "JSON";
+pub const BUFFER: &str = "buffer";
+pub const SCHEMA: &str = "schema";
+pub const ATTESTATION: &str = "attestation";
+pub const JSON_ACCOUNT: &str = "json_account";
 
 pub fn find_metadata_account(json_account: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
@@ -8,3 +12,59 @@ pub fn find_metadata_account(json_account: &Pubkey) -> (Pubkey, u8) {
         &crate::id(),
     )
 }
+
+pub fn find_buffer_account(json_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            json_account.as_ref(),
+            BUFFER.as_bytes(),
+        ],
+        &crate::id(),
+    )
+}
+
+pub fn find_schema_account(json_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            json_account.as_ref(),
+            SCHEMA.as_bytes(),
+        ],
+        &crate::id(),
+    )
+}
+
+/// Finds the address where `attester`'s attestation of a JSON account is recorded.
+pub fn find_attestation_account(json_account: &Pubkey, attester: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            json_account.as_ref(),
+            ATTESTATION.as_bytes(),
+            attester.as_ref(),
+        ],
+        &crate::id(),
+    )
+}
+
+/// Finds the address of a JSON account created with `InitializeWithSeed`.
+///
+/// The `JSON_ACCOUNT` seed keeps these addresses apart from the accounts derived from a JSON
+/// account, whose second seed is also a public key.
+pub fn find_json_account(owner: &Pubkey, seed: &str, mint: Option<&Pubkey>) -> (Pubkey, u8) {
+    let mut seeds = vec![
+        PREFIX.as_bytes(),
+        crate::ID.as_ref(),
+        JSON_ACCOUNT.as_bytes(),
+        owner.as_ref(),
+    ];
+    if let Some(mint) = mint {
+        seeds.push(mint.as_ref());
+    }
+    seeds.push(seed.as_bytes());
+    Pubkey::find_program_address(&seeds, &crate::id())
+}
@@ -0,0 +1,187 @@
+use serde_json::Value;
+
+use crate::error::OnchainMetadataError;
+
+/// Splits an RFC 6901 JSON Pointer into its unescaped reference tokens.
+pub fn parse(pointer: &str) -> Result<Vec<String>, OnchainMetadataError> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    pointer
+        .strip_prefix('/')
+        .ok_or(OnchainMetadataError::InvalidPointer)?
+        .split('/')
+        .map(|token| {
+            let mut unescaped = String::with_capacity(token.len());
+            let mut chars = token.chars();
+            while let Some(c) = chars.next() {
+                if c == '~' {
+                    match chars.next() {
+                        Some('0') => unescaped.push('~'),
+                        Some('1') => unescaped.push('/'),
+                        _ => return Err(OnchainMetadataError::InvalidPointer),
+                    }
+                } else {
+                    unescaped.push(c);
+                }
+            }
+            Ok(unescaped)
+        })
+        .collect()
+}
+
+/// Returns the value referenced by `path`.
+pub fn get_mut<'a>(
+    doc: &'a mut Value,
+    path: &[String],
+) -> Result<&'a mut Value, OnchainMetadataError> {
+    path.iter()
+        .try_fold(doc, |target, token| match target {
+            Value::Object(map) => map.get_mut(token),
+            Value::Array(array) => array.get_mut(array_index(token)?),
+            _ => None,
+        })
+        .ok_or(OnchainMetadataError::PointerNotFound)
+}
+
+/// Inserts `value` at `path` following the RFC 6902 `add` semantics: object members are created
+/// or replaced and array elements are inserted, shifting later elements up.
+pub fn add(doc: &mut Value, path: &[String], value: Value) -> Result<(), OnchainMetadataError> {
+    let (last, parent) = match path.split_last() {
+        Some(split) => split,
+        None => {
+            *doc = value;
+            return Ok(());
+        }
+    };
+
+    match get_mut(doc, parent)? {
+        Value::Object(map) => {
+            map.insert(last.clone(), value);
+        }
+        Value::Array(array) => {
+            let index = push_index(last, array.len())?;
+            array.insert(index, value);
+        }
+        _ => return Err(OnchainMetadataError::PointerNotFound),
+    }
+
+    Ok(())
+}
+
+/// Writes `value` at `path`, creating object members and replacing array elements in place.
+/// Appending to an array is possible with the `-` token or an index equal to its length.
+pub fn set(doc: &mut Value, path: &[String], value: Value) -> Result<(), OnchainMetadataError> {
+    let (last, parent) = match path.split_last() {
+        Some(split) => split,
+        None => {
+            *doc = value;
+            return Ok(());
+        }
+    };
+
+    match get_mut(doc, parent)? {
+        Value::Object(map) => {
+            map.insert(last.clone(), value);
+        }
+        Value::Array(array) => {
+            let index = push_index(last, array.len())?;
+            if index == array.len() {
+                array.push(value);
+            } else {
+                array[index] = value;
+            }
+        }
+        _ => return Err(OnchainMetadataError::PointerNotFound),
+    }
+
+    Ok(())
+}
+
+/// Removes and returns the value at `path`. The document root cannot be removed.
+pub fn remove(doc: &mut Value, path: &[String]) -> Result<Value, OnchainMetadataError> {
+    let (last, parent) = path
+        .split_last()
+        .ok_or(OnchainMetadataError::InvalidPointer)?;
+
+    match get_mut(doc, parent)? {
+        Value::Object(map) => map.remove(last),
+        Value::Array(array) => match array_index(last) {
+            Some(index) if index < array.len() => Some(array.remove(index)),
+            _ => None,
+        },
+        _ => None,
+    }
+    .ok_or(OnchainMetadataError::PointerNotFound)
+}
+
+/// Returns the paths of the smallest locations that differ between `old` and `new`.
+///
+/// Objects are compared member by member and arrays element by element when their lengths match.
+/// Any other difference, including an array changing length, reports the enclosing location.
+pub fn changed_paths(old: &Value, new: &Value) -> Vec<Vec<String>> {
+    let mut paths = Vec::new();
+    collect_changed_paths(old, new, &mut Vec::new(), &mut paths);
+    paths
+}
+
+fn collect_changed_paths(
+    old: &Value,
+    new: &Value,
+    path: &mut Vec<String>,
+    paths: &mut Vec<Vec<String>>,
+) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for key in old
+                .keys()
+                .chain(new.keys().filter(|key| !old.contains_key(*key)))
+            {
+                path.push(key.clone());
+                match (old.get(key), new.get(key)) {
+                    (Some(old), Some(new)) => collect_changed_paths(old, new, path, paths),
+                    _ => paths.push(path.clone()),
+                }
+                path.pop();
+            }
+        }
+        (Value::Array(old), Value::Array(new)) if old.len() == new.len() => {
+            for (index, (old, new)) in old.iter().zip(new).enumerate() {
+                path.push(index.to_string());
+                collect_changed_paths(old, new, path, paths);
+                path.pop();
+            }
+        }
+        _ => {
+            if old != new {
+                paths.push(path.clone());
+            }
+        }
+    }
+}
+
+/// Parses an array index token, rejecting leading zeros as required by RFC 6901.
+pub(crate) fn array_index(token: &str) -> Option<usize> {
+    if token.is_empty()
+        || (token.len() > 1 && token.starts_with('0'))
+        || !token.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    token.parse().ok()
+}
+
+/// Resolves an index that may point one past the end of an array, including the `-` token.
+fn push_index(token: &str, len: usize) -> Result<usize, OnchainMetadataError> {
+    let index = if token == "-" {
+        len
+    } else {
+        array_index(token).ok_or(OnchainMetadataError::PointerNotFound)?
+    };
+
+    if index > len {
+        return Err(OnchainMetadataError::PointerNotFound);
+    }
+    Ok(index)
+}
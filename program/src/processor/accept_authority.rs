@@ -0,0 +1,57 @@
+use borsh::BorshSerialize;
+use mpl_utils::resize_or_reallocate_account_raw;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{error::OnchainMetadataError, event::JsonEvent};
+
+pub(crate) fn process_accept_authority(accounts: &[AccountInfo]) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    // The proposed authority must sign to accept.
+    let authority = accounts.signer()?;
+    let payer = accounts.signer()?;
+    let system_program = accounts.system_program()?;
+
+    let JsonAccounts {
+        json_account,
+        json_metadata_account,
+        mut json_metadata,
+        ..
+    } = accounts;
+
+    // Move the proposal into the authorities.
+    let index = json_metadata
+        .pending_authorities
+        .iter()
+        .position(|entry| &entry.key == authority.key)
+        .ok_or(OnchainMetadataError::ProposalNotFound)?;
+    let entry = json_metadata.pending_authorities.remove(index);
+    json_metadata.set_authority(entry);
+
+    // Replacing the roles of the last admin must not leave the account without one.
+    json_metadata.assert_authorities()?;
+
+    json_metadata.record_change()?;
+
+    // Write the updated JSON metadata account back to the account.
+    let serialized_data = json_metadata.try_to_vec()?;
+
+    // Resize the account to fit the new authority.
+    resize_or_reallocate_account_raw(
+        json_metadata_account,
+        payer,
+        system_program,
+        serialized_data.len(),
+    )?;
+
+    // Write the JSON metadata to the JSON metadata account.
+    sol_memcpy(
+        &mut json_metadata_account.try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
+    JsonEvent::authorities_changed(json_account.key, &json_metadata).emit()
+}
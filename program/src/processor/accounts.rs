@@ -0,0 +1,347 @@
+//! Account validation shared by every instruction.
+
+use borsh::BorshSerialize;
+use mpl_utils::{assert_derivation, assert_signer};
+use serde_json::Value;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    program_memory::sol_memcpy,
+    system_program,
+};
+
+use crate::{
+    error::OnchainMetadataError,
+    event::JsonEvent,
+    pda::{BUFFER, PREFIX},
+    schema,
+    state::{JsonMetadata, BUFFER_HEADER_LEN},
+    utils::resize_and_refund,
+};
+
+/// How an instruction uses the JSON account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Access {
+    /// The JSON data and metadata are only read, which frozen accounts still allow.
+    Read,
+    /// The JSON data or metadata is modified, which requires the account to still be mutable.
+    Write,
+    /// The accounts are closed, which frozen accounts still allow so their rent isn't stranded.
+    Close,
+}
+
+/// A validated JSON account and metadata account, followed by the rest of the instruction's
+/// accounts.
+pub(crate) struct JsonAccounts<'a, 'b> {
+    pub json_account: &'a AccountInfo<'b>,
+    pub json_metadata_account: &'a AccountInfo<'b>,
+    pub json_metadata: JsonMetadata,
+    accounts: std::slice::Iter<'a, AccountInfo<'b>>,
+}
+
+/// The accounts a write to the JSON data takes after the JSON account and metadata account.
+pub(crate) struct Writer<'a, 'b> {
+    pub authority: &'a AccountInfo<'b>,
+    pub payer: &'a AccountInfo<'b>,
+    pub rent_destination: &'a AccountInfo<'b>,
+    pub system_program: &'a AccountInfo<'b>,
+    pub schema_account: Option<&'a AccountInfo<'b>>,
+}
+
+impl<'a, 'b> JsonAccounts<'a, 'b> {
+    /// Takes the JSON account and its metadata account from the front of `accounts`.
+    ///
+    /// Both must be initialized, the metadata account must be at the address derived from the
+    /// JSON account with the stored bump, and accounts loaded for `Access::Write` must not be
+    /// frozen.
+    pub fn load(accounts: &'a [AccountInfo<'b>], access: Access) -> Result<Self, ProgramError> {
+        let mut accounts = accounts.iter();
+        let json_account = next_initialized(&mut accounts)?;
+        let json_metadata_account = next_initialized(&mut accounts)?;
+        let json_metadata = JsonMetadata::load(&json_metadata_account.data.borrow())?;
+
+        let bump = assert_metadata_derivation(json_account, json_metadata_account)?;
+        if bump != json_metadata.bump {
+            return Err(OnchainMetadataError::MetadataDerivedKeyInvalid.into());
+        }
+
+        // Frozen accounts can no longer be modified.
+        if access == Access::Write && !json_metadata.mutable {
+            return Err(OnchainMetadataError::Immutable.into());
+        }
+
+        Ok(Self {
+            json_account,
+            json_metadata_account,
+            json_metadata,
+            accounts,
+        })
+    }
+
+    /// Takes the next account without checking it.
+    pub fn next(&mut self) -> Result<&'a AccountInfo<'b>, ProgramError> {
+        next_account_info(&mut self.accounts)
+    }
+
+    /// Takes the upload buffer, which must already exist at the address derived from the JSON
+    /// account.
+    pub fn buffer_account(&mut self) -> Result<&'a AccountInfo<'b>, ProgramError> {
+        let buffer_account = next_initialized(&mut self.accounts)?;
+        assert_buffer_derivation(self.json_account, buffer_account)?;
+
+        Ok(buffer_account)
+    }
+
+    /// Takes the account at the upload buffer's address, returning it only if an upload is in
+    /// progress.
+    pub fn pending_buffer_account(&mut self) -> Result<Option<&'a AccountInfo<'b>>, ProgramError> {
+        let buffer_account = self.next()?;
+        assert_buffer_derivation(self.json_account, buffer_account)?;
+
+        if (buffer_account.owner != &crate::ID) || buffer_account.data_is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(buffer_account))
+    }
+
+    /// Takes the next account, which must not have been created yet.
+    pub fn uninitialized(&mut self) -> Result<&'a AccountInfo<'b>, ProgramError> {
+        next_uninitialized(&mut self.accounts)
+    }
+
+    /// Takes the next account, which must sign.
+    pub fn signer(&mut self) -> Result<&'a AccountInfo<'b>, ProgramError> {
+        next_signer(&mut self.accounts)
+    }
+
+    /// Takes the next account, which must be the system program.
+    pub fn system_program(&mut self) -> Result<&'a AccountInfo<'b>, ProgramError> {
+        next_system_program(&mut self.accounts)
+    }
+
+    /// Takes the next account, which must sign and hold at least one of `roles`.
+    pub fn authority(&mut self, roles: u8) -> Result<&'a AccountInfo<'b>, ProgramError> {
+        let authority = self.signer()?;
+        if !self.json_metadata.has_role(authority.key, roles) {
+            return Err(OnchainMetadataError::InvalidAuthority.into());
+        }
+
+        Ok(authority)
+    }
+
+    /// Takes the attached schema account, which follows the fixed accounts whenever the JSON
+    /// account has a schema.
+    pub fn schema_account(&mut self) -> Result<Option<&'a AccountInfo<'b>>, ProgramError> {
+        match self.json_metadata.schema {
+            Some(schema) => {
+                let schema_account = self.next()?;
+                if schema_account.key != &schema {
+                    return Err(OnchainMetadataError::SchemaDerivedKeyInvalid.into());
+                }
+                Ok(Some(schema_account))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Checks that `authority`, together with the remaining accounts co-signing for `roles`,
+    /// meets the threshold.
+    pub fn assert_threshold(&self, authority: &AccountInfo, roles: u8) -> ProgramResult {
+        self.json_metadata
+            .assert_threshold(authority, self.accounts.as_slice(), roles)
+    }
+
+    /// Takes the accounts of a write by an authority holding one of `roles`, checking that it
+    /// meets the threshold and that the JSON data is still at `expected_revision`.
+    pub fn writer(
+        &mut self,
+        roles: u8,
+        expected_revision: Option<u64>,
+    ) -> Result<Writer<'a, 'b>, ProgramError> {
+        let authority = self.authority(roles)?;
+        let payer = self.signer()?;
+        let rent_destination = self.next()?;
+        let system_program = self.system_program()?;
+
+        // An attached schema account follows the fixed accounts.
+        let schema_account = self.schema_account()?;
+
+        // Any further accounts are authorities co-signing to meet the threshold.
+        self.assert_threshold(authority, roles)?;
+
+        // Writers may ask to only apply their change on top of the revision they read.
+        self.json_metadata.assert_revision(expected_revision)?;
+
+        Ok(Writer {
+            authority,
+            payer,
+            rent_destination,
+            system_program,
+            schema_account,
+        })
+    }
+
+    /// Decodes the JSON data stored in the JSON account.
+    pub fn value(&self) -> Result<Value, ProgramError> {
+        self.json_metadata
+            .encoding
+            .deserialize(&self.json_account.data.borrow())
+    }
+
+    /// Replaces the JSON data with `json_data` on behalf of `writer`, recording the write and
+    /// emitting a `ValueChanged` event for `changed_paths`.
+    ///
+    /// Delegated authorities may only change the paths they were granted, and documents with an
+    /// attached schema must still conform to it.
+    pub fn write(
+        &mut self,
+        writer: &Writer<'a, 'b>,
+        json_data: &Value,
+        changed_paths: Vec<String>,
+    ) -> ProgramResult {
+        self.json_metadata.assert_paths(
+            writer.authority.key,
+            &self.json_account.data.borrow(),
+            json_data,
+        )?;
+        if let Some(schema_account) = writer.schema_account {
+            schema::validate_account(schema_account, json_data)?;
+        }
+
+        self.store(writer, json_data, changed_paths)
+    }
+
+    /// Stores `json_data` like `write` without checking it, for documents that were already
+    /// accepted and are only being re-encoded.
+    pub fn store(
+        &mut self,
+        writer: &Writer<'a, 'b>,
+        json_data: &Value,
+        changed_paths: Vec<String>,
+    ) -> ProgramResult {
+        let serialized_data = self.json_metadata.encoding.serialize(json_data)?;
+
+        // Resize the account to fit the new data, refunding any rent it no longer needs.
+        resize_and_refund(
+            self.json_account,
+            writer.payer,
+            writer.rent_destination,
+            writer.system_program,
+            serialized_data.len(),
+        )?;
+
+        // Write the JSON data to the JSON account.
+        sol_memcpy(
+            &mut self.json_account.try_borrow_mut_data()?,
+            &serialized_data,
+            serialized_data.len(),
+        );
+
+        // Bump the revision and hash. The metadata size doesn't change, so it isn't reallocated.
+        self.json_metadata.record_write(json_data)?;
+        let serialized_metadata = self.json_metadata.try_to_vec()?;
+        sol_memcpy(
+            &mut self.json_metadata_account.try_borrow_mut_data()?,
+            &serialized_metadata,
+            serialized_metadata.len(),
+        );
+
+        JsonEvent::value_changed(self.json_account.key, &self.json_metadata, changed_paths).emit()
+    }
+}
+
+/// Takes the next account, which must be owned by this program and hold data.
+pub(crate) fn next_initialized<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
+    accounts: &mut I,
+) -> Result<&'a AccountInfo<'b>, ProgramError> {
+    let account = next_account_info(accounts)?;
+    if (account.owner != &crate::ID) || account.data_is_empty() {
+        return Err(OnchainMetadataError::NotInitialized.into());
+    }
+
+    Ok(account)
+}
+
+/// Takes the next account, which must not have been created yet.
+pub(crate) fn next_uninitialized<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
+    accounts: &mut I,
+) -> Result<&'a AccountInfo<'b>, ProgramError> {
+    let account = next_account_info(accounts)?;
+    if (account.owner != &system_program::ID) || !account.data_is_empty() {
+        return Err(OnchainMetadataError::AlreadyInitialized.into());
+    }
+
+    Ok(account)
+}
+
+/// Takes the next account, which must sign.
+pub(crate) fn next_signer<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
+    accounts: &mut I,
+) -> Result<&'a AccountInfo<'b>, ProgramError> {
+    let account = next_account_info(accounts)?;
+    assert_signer(account)?;
+
+    Ok(account)
+}
+
+/// Takes the next account, which must be the system program.
+pub(crate) fn next_system_program<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
+    accounts: &mut I,
+) -> Result<&'a AccountInfo<'b>, ProgramError> {
+    let account = next_account_info(accounts)?;
+    if account.key != &system_program::ID {
+        return Err(OnchainMetadataError::InvalidSystemProgram.into());
+    }
+
+    Ok(account)
+}
+
+/// Checks that `json_metadata_account` is at the address derived from `json_account`, returning
+/// its bump.
+pub(crate) fn assert_metadata_derivation(
+    json_account: &AccountInfo,
+    json_metadata_account: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    assert_derivation(
+        &crate::ID,
+        json_metadata_account,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            json_account.key.as_ref(),
+        ],
+        OnchainMetadataError::MetadataDerivedKeyInvalid,
+    )
+}
+
+/// Checks that `authority` began the upload staged in `buffer_account`.
+pub(crate) fn assert_uploader(
+    buffer_account: &AccountInfo,
+    authority: &AccountInfo,
+) -> ProgramResult {
+    if buffer_account.data.borrow().get(..BUFFER_HEADER_LEN) != Some(authority.key.as_ref()) {
+        return Err(OnchainMetadataError::InvalidUploader.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that `buffer_account` is at the upload buffer address derived from `json_account`.
+pub(crate) fn assert_buffer_derivation(
+    json_account: &AccountInfo,
+    buffer_account: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    assert_derivation(
+        &crate::ID,
+        buffer_account,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            json_account.key.as_ref(),
+            BUFFER.as_bytes(),
+        ],
+        OnchainMetadataError::BufferDerivedKeyInvalid,
+    )
+}
@@ -1,63 +1,55 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use mpl_utils::{assert_derivation, assert_signer, resize_or_reallocate_account_raw};
+use borsh::BorshSerialize;
+use mpl_utils::resize_or_reallocate_account_raw;
 use solana_program::{
-    account_info::{next_account_info, AccountInfo},
-    entrypoint::ProgramResult,
-    program_memory::sol_memcpy,
-    system_program,
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
 };
 
+use super::accounts::{Access, JsonAccounts};
 use crate::{
-    error::OnchainMetadataError, instruction::AddAuthorityArgs, pda::PREFIX, state::JsonMetadata,
+    error::OnchainMetadataError,
+    event::JsonEvent,
+    instruction::AddAuthorityArgs,
+    state::{AuthorityEntry, ROLE_ADMIN},
 };
 
 pub(crate) fn process_add_authority(
     accounts: &[AccountInfo],
     args: AddAuthorityArgs,
 ) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
-    let json_account = next_account_info(account_info_iter)?;
-    // Check that the account isn't already initialized.
-    if (json_account.owner != &crate::ID) || json_account.data_is_empty() {
-        return Err(OnchainMetadataError::NotInitialized.into());
-    }
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let authority = accounts.authority(ROLE_ADMIN)?;
+    let payer = accounts.signer()?;
+    let system_program = accounts.system_program()?;
 
-    let json_metadata_account = next_account_info(account_info_iter)?;
-    // Check that the account isn't already initialized.
-    if (json_metadata_account.owner != &crate::ID) || json_metadata_account.data_is_empty() {
-        return Err(OnchainMetadataError::NotInitialized.into());
-    }
-    let mut json_metadata = JsonMetadata::try_from_slice(&json_metadata_account.data.borrow())?;
+    // Any further accounts are authorities co-signing to meet the threshold.
+    accounts.assert_threshold(authority, ROLE_ADMIN)?;
 
-    // Verify that the derived address is correct for the JSON metadata account.
-    let bump = assert_derivation(
-        &crate::ID,
+    let JsonAccounts {
+        json_account,
         json_metadata_account,
-        &[
-            PREFIX.as_bytes(),
-            crate::ID.as_ref(),
-            json_account.key.as_ref(),
-        ],
-        OnchainMetadataError::MetadataDerivedKeyInvalid,
-    )?;
-    if bump != json_metadata.bump {
-        return Err(OnchainMetadataError::MetadataDerivedKeyInvalid.into());
-    }
+        mut json_metadata,
+        ..
+    } = accounts;
 
-    let payer = next_account_info(account_info_iter)?;
-    // The payer and authority must sign.
-    assert_signer(payer)?;
-    if !json_metadata.authorities.contains(payer.key) {
-        return Err(OnchainMetadataError::InvalidAuthority.into());
+    // Admins must prove they control their key by accepting a proposal, so only keys that are
+    // already admins may keep the role here.
+    if args.roles & ROLE_ADMIN != 0 && !json_metadata.has_role(&args.new_authority, ROLE_ADMIN) {
+        return Err(OnchainMetadataError::AdminRequiresProposal.into());
     }
 
-    let system_program = next_account_info(account_info_iter)?;
-    if system_program.key != &system_program::ID {
-        return Err(OnchainMetadataError::InvalidSystemProgram.into());
-    }
+    // Add the new authority, or replace the roles and paths of an existing one.
+    let entry = AuthorityEntry {
+        key: args.new_authority,
+        roles: args.roles,
+        paths: args.paths,
+    };
+    entry.check()?;
+    json_metadata.set_authority(entry);
+
+    // Replacing the roles of the last admin must not leave the account without one.
+    json_metadata.assert_authorities()?;
 
-    // Add the new authority.
-    json_metadata.authorities.push(args.new_authority);
+    json_metadata.record_change()?;
 
     // Write the updated JSON metadata account back to the account.
     let serialized_data = json_metadata.try_to_vec()?;
@@ -77,5 +69,5 @@ pub(crate) fn process_add_authority(
         serialized_data.len(),
     );
 
-    Ok(())
+    JsonEvent::authorities_changed(json_account.key, &json_metadata).emit()
 }
@@ -0,0 +1,146 @@
+use borsh::BorshSerialize;
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    ed25519_program,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    program_memory::sol_memcpy,
+    pubkey::Pubkey,
+    sysvar::{
+        instructions::{load_current_index_checked, load_instruction_at_checked},
+        Sysvar,
+    },
+};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{
+    error::OnchainMetadataError,
+    pda::{ATTESTATION, PREFIX},
+    state::Attestation,
+};
+
+pub(crate) fn process_attest(accounts: &[AccountInfo]) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Read)?;
+    let attestation_account = accounts.next()?;
+
+    // The signature was checked by the ed25519 program, so anyone may pay to record it.
+    let payer = accounts.signer()?;
+    let system_program = accounts.system_program()?;
+    let instructions_sysvar = accounts.next()?;
+
+    let JsonAccounts {
+        json_account,
+        json_metadata,
+        ..
+    } = accounts;
+
+    let (attester, message) = verified_signature(instructions_sysvar)?;
+
+    // The attester must have signed the data as it is now.
+    if message
+        != Attestation::message(
+            json_account.key,
+            json_metadata.revision,
+            &json_metadata.hash,
+        )
+    {
+        return Err(OnchainMetadataError::AttestationMismatch.into());
+    }
+
+    // Verify that the derived address is correct for the attester's attestation account.
+    let attestation_bump = assert_derivation(
+        &crate::ID,
+        attestation_account,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            json_account.key.as_ref(),
+            ATTESTATION.as_bytes(),
+            attester.as_ref(),
+        ],
+        OnchainMetadataError::AttestationDerivedKeyInvalid,
+    )?;
+
+    let attestation = Attestation {
+        bump: attestation_bump,
+        json_account: *json_account.key,
+        attester,
+        revision: json_metadata.revision,
+        hash: json_metadata.hash,
+        slot: Clock::get()?.slot,
+    };
+    let serialized_data = attestation.try_to_vec()?;
+
+    // Attestations have a fixed size, so a newer one simply overwrites the last.
+    if attestation_account.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::ID,
+            attestation_account,
+            system_program,
+            payer,
+            serialized_data.len(),
+            &[
+                PREFIX.as_bytes(),
+                crate::ID.as_ref(),
+                json_account.key.as_ref(),
+                ATTESTATION.as_bytes(),
+                attester.as_ref(),
+                &[attestation_bump],
+            ],
+        )?;
+    }
+
+    // Write the attestation to the attestation account.
+    sol_memcpy(
+        &mut attestation_account.try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
+    Ok(())
+}
+
+/// Returns the public key and message of the signature verified by the ed25519 program in the
+/// instruction before this one.
+///
+/// Only a single signature whose key, signature and message all live in that instruction's own
+/// data is accepted, so the offsets can't point at data the ed25519 program didn't verify.
+fn verified_signature(
+    instructions_sysvar: &AccountInfo,
+) -> Result<(Pubkey, Vec<u8>), ProgramError> {
+    let current = load_current_index_checked(instructions_sysvar)? as usize;
+    let previous = current
+        .checked_sub(1)
+        .ok_or(OnchainMetadataError::InvalidSignatureInstruction)?;
+    let instruction = load_instruction_at_checked(previous, instructions_sysvar)?;
+    if instruction.program_id != ed25519_program::ID {
+        return Err(OnchainMetadataError::InvalidSignatureInstruction.into());
+    }
+
+    // The data starts with the signature count and a padding byte, followed by seven offsets:
+    // the signature, public key and message offsets, each with the index of the instruction
+    // holding them, and the message size.
+    let data = &instruction.data;
+    if data.len() < 16 || data[0] != 1 {
+        return Err(OnchainMetadataError::InvalidSignatureInstruction.into());
+    }
+    let offset = |i: usize| u16::from_le_bytes([data[2 + 2 * i], data[3 + 2 * i]]);
+    if [offset(1), offset(3), offset(6)] != [u16::MAX; 3] {
+        return Err(OnchainMetadataError::InvalidSignatureInstruction.into());
+    }
+    let public_key_offset = offset(2) as usize;
+    let message_offset = offset(4) as usize;
+    let message_size = offset(5) as usize;
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .and_then(|bytes| Pubkey::try_from(bytes).ok())
+        .ok_or(OnchainMetadataError::InvalidSignatureInstruction)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(OnchainMetadataError::InvalidSignatureInstruction)?;
+
+    Ok((public_key, message.to_vec()))
+}
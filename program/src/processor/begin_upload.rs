@@ -0,0 +1,67 @@
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{
+    error::OnchainMetadataError,
+    instruction::BeginUploadArgs,
+    pda::{BUFFER, PREFIX},
+    state::{BUFFER_HEADER_LEN, ROLE_WRITER},
+};
+
+pub(crate) fn process_begin_upload(
+    accounts: &[AccountInfo],
+    args: BeginUploadArgs,
+) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    // Check that there isn't already an upload in progress.
+    let buffer_account = accounts.uninitialized()?;
+    // Verify that the derived address is correct for the buffer account.
+    let buffer_bump = assert_derivation(
+        &crate::ID,
+        buffer_account,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            accounts.json_account.key.as_ref(),
+            BUFFER.as_bytes(),
+        ],
+        OnchainMetadataError::BufferDerivedKeyInvalid,
+    )?;
+
+    let authority = accounts.authority(ROLE_WRITER)?;
+    let payer = accounts.signer()?;
+    let system_program = accounts.system_program()?;
+
+    // Any further accounts are authorities co-signing to meet the threshold.
+    accounts.assert_threshold(authority, ROLE_WRITER)?;
+
+    let JsonAccounts { json_account, .. } = accounts;
+
+    if args.size == 0 {
+        return Err(OnchainMetadataError::ChunkOutOfBounds.into());
+    }
+
+    // Allocate the zeroed buffer that chunks will be written into, after the header.
+    solana_program::msg!("Creating upload buffer account");
+    create_or_allocate_account_raw(
+        crate::ID,
+        buffer_account,
+        system_program,
+        payer,
+        BUFFER_HEADER_LEN + args.size as usize,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            json_account.key.as_ref(),
+            BUFFER.as_bytes(),
+            &[buffer_bump],
+        ],
+    )?;
+
+    // Record the uploader so that no other authority can write to, commit or cancel the upload.
+    buffer_account.try_borrow_mut_data()?[..BUFFER_HEADER_LEN]
+        .copy_from_slice(authority.key.as_ref());
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use borsh::BorshSerialize;
+use mpl_utils::resize_or_reallocate_account_raw;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{
+    error::OnchainMetadataError, event::JsonEvent, instruction::CancelProposalArgs,
+    state::ROLE_ADMIN,
+};
+
+pub(crate) fn process_cancel_proposal(
+    accounts: &[AccountInfo],
+    args: CancelProposalArgs,
+) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    // An admin or the proposed authority must sign.
+    let authority = accounts.signer()?;
+    if !accounts.json_metadata.has_role(authority.key, ROLE_ADMIN)
+        && authority.key != &args.authority
+    {
+        return Err(OnchainMetadataError::InvalidAuthority.into());
+    }
+    let payer = accounts.signer()?;
+    let system_program = accounts.system_program()?;
+
+    // Admins cancelling on behalf of the account must meet the threshold, while the proposed
+    // authority can always decline on its own.
+    if authority.key != &args.authority {
+        accounts.assert_threshold(authority, ROLE_ADMIN)?;
+    }
+
+    let JsonAccounts {
+        json_account,
+        json_metadata_account,
+        mut json_metadata,
+        ..
+    } = accounts;
+
+    // Remove the proposal.
+    let index = json_metadata
+        .pending_authorities
+        .iter()
+        .position(|entry| entry.key == args.authority)
+        .ok_or(OnchainMetadataError::ProposalNotFound)?;
+    json_metadata.pending_authorities.remove(index);
+
+    json_metadata.record_change()?;
+
+    // Write the updated JSON metadata account back to the account.
+    let serialized_data = json_metadata.try_to_vec()?;
+
+    // Shrink the account now that the proposal is gone.
+    resize_or_reallocate_account_raw(
+        json_metadata_account,
+        payer,
+        system_program,
+        serialized_data.len(),
+    )?;
+
+    // Write the JSON metadata to the JSON metadata account.
+    sol_memcpy(
+        &mut json_metadata_account.try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
+    JsonEvent::proposals_changed(json_account.key, &json_metadata).emit()
+}
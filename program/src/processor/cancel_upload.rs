@@ -0,0 +1,22 @@
+use mpl_utils::close_account_raw;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use super::accounts::{assert_uploader, Access, JsonAccounts};
+use crate::state::ROLE_WRITER;
+
+pub(crate) fn process_cancel_upload(accounts: &[AccountInfo]) -> ProgramResult {
+    // Cancelling only discards the buffer, so it's still allowed once the account is frozen.
+    let mut accounts = JsonAccounts::load(accounts, Access::Read)?;
+    let buffer_account = accounts.buffer_account()?;
+    let authority = accounts.authority(ROLE_WRITER)?;
+    assert_uploader(buffer_account, authority)?;
+    let rent_destination = accounts.next()?;
+
+    // Any further accounts are authorities co-signing to meet the threshold.
+    accounts.assert_threshold(authority, ROLE_WRITER)?;
+
+    // Discard the buffer and release its rent.
+    close_account_raw(rent_destination, buffer_account)?;
+
+    Ok(())
+}
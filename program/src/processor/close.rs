@@ -1,58 +1,42 @@
-use borsh::BorshDeserialize;
-use mpl_utils::{assert_derivation, assert_signer, close_account_raw};
-use solana_program::{
-    account_info::{next_account_info, AccountInfo},
-    entrypoint::ProgramResult,
-    system_program,
-};
+use mpl_utils::close_account_raw;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
 
-use crate::{error::OnchainMetadataError, pda::PREFIX, state::JsonMetadata};
+use super::accounts::{Access, JsonAccounts};
+use crate::{event::JsonEvent, state::ROLE_ADMIN};
 
 pub(crate) fn process_close(accounts: &[AccountInfo]) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
-    let json_account = next_account_info(account_info_iter)?;
-    // Check that the account isn't already initialized.
-    if (json_account.owner != &crate::ID) || json_account.data_is_empty() {
-        return Err(OnchainMetadataError::NotInitialized.into());
-    }
+    let mut accounts = JsonAccounts::load(accounts, Access::Close)?;
+    let authority = accounts.authority(ROLE_ADMIN)?;
+    let rent_destination = accounts.next()?;
+    accounts.system_program()?;
 
-    let json_metadata_account = next_account_info(account_info_iter)?;
-    // Check that the account isn't already initialized.
-    if (json_metadata_account.owner != &crate::ID) || json_metadata_account.data_is_empty() {
-        return Err(OnchainMetadataError::NotInitialized.into());
-    }
-    let json_metadata = JsonMetadata::try_from_slice(&json_metadata_account.data.borrow())?;
+    // An upload left in progress would strand the buffer's rent, so it's closed too.
+    let buffer_account = accounts.pending_buffer_account()?;
+
+    // An attached schema would be orphaned, so it's closed along with the JSON account.
+    let schema_account = accounts.schema_account()?;
+
+    // Any further accounts are authorities co-signing to meet the threshold.
+    accounts.assert_threshold(authority, ROLE_ADMIN)?;
 
-    // Verify that the derived address is correct for the JSON metadata account.
-    let bump = assert_derivation(
-        &crate::ID,
+    let JsonAccounts {
+        json_account,
         json_metadata_account,
-        &[
-            PREFIX.as_bytes(),
-            crate::ID.as_ref(),
-            json_account.key.as_ref(),
-        ],
-        OnchainMetadataError::MetadataDerivedKeyInvalid,
-    )?;
-    if bump != json_metadata.bump {
-        return Err(OnchainMetadataError::MetadataDerivedKeyInvalid.into());
+        ..
+    } = accounts;
+
+    // Close both accounts, sending their rent to the chosen destination.
+    close_account_raw(rent_destination, json_account)?;
+    close_account_raw(rent_destination, json_metadata_account)?;
+    if let Some(buffer_account) = buffer_account {
+        close_account_raw(rent_destination, buffer_account)?;
     }
-
-    let payer = next_account_info(account_info_iter)?;
-    // The payer and authority must sign.
-    assert_signer(payer)?;
-    if !json_metadata.authorities.contains(payer.key) {
-        return Err(OnchainMetadataError::InvalidAuthority.into());
+    if let Some(schema_account) = schema_account {
+        close_account_raw(rent_destination, schema_account)?;
     }
 
-    let system_program = next_account_info(account_info_iter)?;
-    if system_program.key != &system_program::ID {
-        return Err(OnchainMetadataError::InvalidSystemProgram.into());
+    JsonEvent::Close {
+        json_account: *json_account.key,
     }
-
-    // Close both accounts
-    close_account_raw(payer, json_account)?;
-    close_account_raw(payer, json_metadata_account)?;
-
-    Ok(())
+    .emit()
 }
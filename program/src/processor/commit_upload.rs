@@ -0,0 +1,43 @@
+use mpl_utils::close_account_raw;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use super::{
+    accounts::{assert_uploader, Access, JsonAccounts},
+    set_value::merge,
+};
+use crate::{
+    error::OnchainMetadataError,
+    event,
+    instruction::CommitUploadArgs,
+    state::{BUFFER_HEADER_LEN, ROLE_WRITER},
+};
+
+pub(crate) fn process_commit_upload(
+    accounts: &[AccountInfo],
+    args: CommitUploadArgs,
+) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let buffer_account = accounts.buffer_account()?;
+    let writer = accounts.writer(ROLE_WRITER, args.expected_revision)?;
+    assert_uploader(buffer_account, writer.authority)?;
+
+    // The assembled buffer must be a complete JSON document.
+    let uploaded_data: serde_json::Value =
+        serde_json::from_slice(&buffer_account.data.borrow()[BUFFER_HEADER_LEN..])
+            .map_err(|_| OnchainMetadataError::InvalidJson)?;
+
+    // A merge writes the leaves of the upload, while a replacement writes the whole document.
+    let (json_data, changed_paths) = if args.merge {
+        let changed_paths = event::merge_paths(&uploaded_data);
+        let mut json_data = accounts.value().unwrap_or(serde_json::Value::Null);
+        merge(&mut json_data, uploaded_data);
+        (json_data, changed_paths)
+    } else {
+        (uploaded_data, vec![String::new()])
+    };
+
+    accounts.write(&writer, &json_data, changed_paths)?;
+
+    // The upload is complete, so release the buffer's rent.
+    close_account_raw(writer.rent_destination, buffer_account)
+}
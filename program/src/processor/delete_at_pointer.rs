@@ -0,0 +1,19 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{instruction::DeleteAtPointerArgs, pointer, state::ROLE_WRITER};
+
+pub(crate) fn process_delete_at_pointer(
+    accounts: &[AccountInfo],
+    args: DeleteAtPointerArgs,
+) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let writer = accounts.writer(ROLE_WRITER, args.expected_revision)?;
+
+    let mut json_data = accounts.value()?;
+
+    let path = pointer::parse(&args.pointer)?;
+    pointer::remove(&mut json_data, &path)?;
+
+    accounts.write(&writer, &json_data, vec![args.pointer])
+}
@@ -0,0 +1,41 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{event::JsonEvent, state::ROLE_ADMIN};
+
+pub(crate) fn process_freeze(accounts: &[AccountInfo]) -> ProgramResult {
+    // Freezing is permanent, so an already frozen account can't be frozen again.
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let authority = accounts.authority(ROLE_ADMIN)?;
+
+    // Any further accounts are authorities co-signing to meet the threshold.
+    accounts.assert_threshold(authority, ROLE_ADMIN)?;
+
+    let JsonAccounts {
+        json_account,
+        json_metadata_account,
+        mut json_metadata,
+        ..
+    } = accounts;
+
+    json_metadata.mutable = false;
+
+    json_metadata.record_change()?;
+
+    // Write the updated JSON metadata account back to the account. The size doesn't change so
+    // no reallocation is needed.
+    let serialized_data = json_metadata.try_to_vec()?;
+    sol_memcpy(
+        &mut json_metadata_account.try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
+    JsonEvent::Freeze {
+        json_account: *json_account.key,
+    }
+    .emit()
+}
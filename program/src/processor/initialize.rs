@@ -1,5 +1,5 @@
 use borsh::BorshSerialize;
-use mpl_utils::{assert_derivation, assert_signer, create_or_allocate_account_raw};
+use mpl_utils::create_or_allocate_account_raw;
 use num_traits::ToPrimitive;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
@@ -7,47 +7,28 @@ use solana_program::{
     program::invoke,
     program_memory::sol_memcpy,
     rent::Rent,
-    system_instruction, system_program,
+    system_instruction,
     sysvar::Sysvar,
 };
 
-use crate::{error::OnchainMetadataError, pda::PREFIX, state::JsonMetadata};
+use super::accounts::{
+    assert_metadata_derivation, next_signer, next_system_program, next_uninitialized,
+};
+use crate::{
+    canonical, error::OnchainMetadataError, event::JsonEvent, pda::PREFIX, state::JsonMetadata,
+};
 
 pub(crate) fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
-    let json_account = next_account_info(account_info_iter)?;
-    // Check that the account isn't already initialized.
-    if (json_account.owner != &system_program::ID) || !json_account.data_is_empty() {
-        return Err(OnchainMetadataError::AlreadyInitialized.into());
-    }
+    // Check that neither account is already initialized.
+    let json_account = next_uninitialized(account_info_iter)?;
+    let json_metadata_account = next_uninitialized(account_info_iter)?;
+    let bump = assert_metadata_derivation(json_account, json_metadata_account)?;
 
-    let json_metadata_account = next_account_info(account_info_iter)?;
-    // Check that the account isn't already initialized.
-    if (json_metadata_account.owner != &system_program::ID)
-        || !json_metadata_account.data_is_empty()
-    {
-        return Err(OnchainMetadataError::AlreadyInitialized.into());
-    }
-    // Verify that the derived address is correct for the JSON metadata account.
-    let bump = assert_derivation(
-        &crate::ID,
-        json_metadata_account,
-        &[
-            PREFIX.as_bytes(),
-            crate::ID.as_ref(),
-            json_account.key.as_ref(),
-        ],
-        OnchainMetadataError::MetadataDerivedKeyInvalid,
-    )?;
-
-    let payer = next_account_info(account_info_iter)?;
-    // The payer and authority must sign.
-    assert_signer(payer)?;
-
-    let system_program = next_account_info(account_info_iter)?;
-    if system_program.key != &system_program::ID {
-        return Err(OnchainMetadataError::InvalidSystemProgram.into());
-    }
+    // The authority needn't sign, so a sponsor can pay to create the account on its behalf.
+    let authority = next_account_info(account_info_iter)?;
+    let payer = next_signer(account_info_iter)?;
+    let system_program = next_system_program(account_info_iter)?;
 
     // Initialize the JSON data with a null value.
     let json_data = serde_json::Value::Null;
@@ -71,12 +52,16 @@ pub(crate) fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
         &[payer.clone(), json_account.clone(), system_program.clone()],
     )?;
 
+    // Write the JSON data to the JSON account.
+    sol_memcpy(
+        &mut json_account.try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
     // Initialize the JSON metadata.
-    let json_metadata = JsonMetadata {
-        bump,
-        mutable: true,
-        authorities: vec![*payer.key],
-    };
+    let mut json_metadata = JsonMetadata::new(bump, *authority.key);
+    json_metadata.hash = canonical::hash(&json_data);
 
     let serialized_metadata = &json_metadata.try_to_vec()?;
 
@@ -103,5 +88,9 @@ pub(crate) fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
         serialized_metadata.len(),
     );
 
-    Ok(())
+    JsonEvent::Initialize {
+        json_account: *json_account.key,
+        authority: *authority.key,
+    }
+    .emit()
 }
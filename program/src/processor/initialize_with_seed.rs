@@ -0,0 +1,135 @@
+use borsh::BorshSerialize;
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_memory::sol_memcpy,
+    pubkey,
+    pubkey::{Pubkey, MAX_SEED_LEN},
+};
+
+use super::accounts::{
+    assert_metadata_derivation, next_signer, next_system_program, next_uninitialized,
+};
+use crate::{
+    canonical,
+    error::OnchainMetadataError,
+    event::JsonEvent,
+    instruction::InitializeWithSeedArgs,
+    pda::{JSON_ACCOUNT, PREFIX},
+    state::JsonMetadata,
+};
+
+/// The programs whose accounts may be used as mints: SPL Token and Token-2022.
+const TOKEN_PROGRAMS: [Pubkey; 2] = [
+    pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"),
+];
+
+pub(crate) fn process_initialize_with_seed(
+    accounts: &[AccountInfo],
+    args: InitializeWithSeedArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Check that neither account is already initialized.
+    let json_account = next_uninitialized(account_info_iter)?;
+    let json_metadata_account = next_uninitialized(account_info_iter)?;
+    let bump = assert_metadata_derivation(json_account, json_metadata_account)?;
+
+    // The owner becomes the first authority. It needn't sign, so a sponsor can pay to create
+    // the account on its behalf.
+    let owner = next_account_info(account_info_iter)?;
+    let payer = next_signer(account_info_iter)?;
+    let system_program = next_system_program(account_info_iter)?;
+
+    // A mint the JSON account is derived from must be a real token mint.
+    if let Some(mint) = &args.mint {
+        let mint_account = next_account_info(account_info_iter)?;
+        if mint_account.key != mint || !TOKEN_PROGRAMS.contains(mint_account.owner) {
+            return Err(OnchainMetadataError::InvalidMint.into());
+        }
+    }
+
+    // Verify that the JSON account is derived from the owner, the optional mint and the seed.
+    if args.seed.is_empty() || args.seed.len() > MAX_SEED_LEN {
+        return Err(OnchainMetadataError::InvalidSeed.into());
+    }
+    let mut json_seeds = vec![
+        PREFIX.as_bytes(),
+        crate::ID.as_ref(),
+        JSON_ACCOUNT.as_bytes(),
+        owner.key.as_ref(),
+    ];
+    if let Some(mint) = &args.mint {
+        json_seeds.push(mint.as_ref());
+    }
+    json_seeds.push(args.seed.as_bytes());
+    let json_bump = assert_derivation(
+        &crate::ID,
+        json_account,
+        &json_seeds,
+        OnchainMetadataError::JsonDerivedKeyInvalid,
+    )?;
+
+    // Initialize the JSON data with a null value.
+    let json_data = serde_json::Value::Null;
+    let serialized_data = match serde_json::to_vec(&json_data) {
+        Ok(data) => data,
+        Err(_) => return Err(OnchainMetadataError::InvalidJson.into()),
+    };
+
+    // Initialize the JSON account.
+    solana_program::msg!("Creating JSON account");
+    let json_bump_seed = [json_bump];
+    json_seeds.push(&json_bump_seed);
+    create_or_allocate_account_raw(
+        crate::ID,
+        json_account,
+        system_program,
+        payer,
+        serialized_data.len(),
+        &json_seeds,
+    )?;
+
+    // Write the JSON data to the JSON account.
+    sol_memcpy(
+        &mut json_account.try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
+    // Initialize the JSON metadata.
+    let mut json_metadata = JsonMetadata::new(bump, *owner.key);
+    json_metadata.hash = canonical::hash(&json_data);
+
+    let serialized_metadata = &json_metadata.try_to_vec()?;
+
+    // Initialize the JSON metadata account.
+    solana_program::msg!("Creating JSON Metadata account");
+    create_or_allocate_account_raw(
+        crate::ID,
+        json_metadata_account,
+        system_program,
+        payer,
+        serialized_metadata.len(),
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            json_account.key.as_ref(),
+            &[bump],
+        ],
+    )?;
+
+    // Write the JSON metadata to the JSON metadata account.
+    sol_memcpy(
+        &mut json_metadata_account.try_borrow_mut_data()?,
+        serialized_metadata,
+        serialized_metadata.len(),
+    );
+
+    JsonEvent::Initialize {
+        json_account: *json_account.key,
+        authority: *owner.key,
+    }
+    .emit()
+}
@@ -0,0 +1,71 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::resize_or_reallocate_account_raw;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+};
+
+use super::accounts::{
+    assert_metadata_derivation, next_initialized, next_signer, next_system_program,
+};
+use crate::{
+    canonical,
+    error::OnchainMetadataError,
+    event::JsonEvent,
+    state::{JsonMetadata, LegacyJsonMetadata, METADATA_VERSION},
+};
+
+pub(crate) fn process_migrate(accounts: &[AccountInfo]) -> ProgramResult {
+    // Legacy metadata can't be loaded as `JsonMetadata`, so the accounts are checked one by one.
+    let account_info_iter = &mut accounts.iter();
+    let json_account = next_initialized(account_info_iter)?;
+    let json_metadata_account = next_initialized(account_info_iter)?;
+
+    // Accounts that already use the current layout are left untouched.
+    if json_metadata_account.data.borrow().get(1) == Some(&METADATA_VERSION) {
+        return Ok(());
+    }
+    let legacy_metadata =
+        LegacyJsonMetadata::deserialize(&mut &json_metadata_account.data.borrow()[..])?;
+
+    let bump = assert_metadata_derivation(json_account, json_metadata_account)?;
+    if bump != legacy_metadata.bump {
+        return Err(OnchainMetadataError::MetadataDerivedKeyInvalid.into());
+    }
+
+    // Migration doesn't change who controls the account, so anyone may pay for it.
+    let payer = next_signer(account_info_iter)?;
+    let system_program = next_system_program(account_info_iter)?;
+
+    // Legacy accounts never stored a hash, so commit to the data as it stands.
+    let mut json_metadata = JsonMetadata::from(legacy_metadata);
+    let json_data = json_metadata
+        .encoding
+        .deserialize(&json_account.data.borrow())?;
+    json_metadata.hash = canonical::hash(&json_data);
+    json_metadata.record_change()?;
+
+    // Write the migrated JSON metadata back to the account.
+    let serialized_data = json_metadata.try_to_vec()?;
+
+    // Resize the account to fit the new layout.
+    resize_or_reallocate_account_raw(
+        json_metadata_account,
+        payer,
+        system_program,
+        serialized_data.len(),
+    )?;
+
+    // Write the JSON metadata to the JSON metadata account.
+    sol_memcpy(
+        &mut json_metadata_account.try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
+    JsonEvent::Migrate {
+        json_account: *json_account.key,
+        revision: json_metadata.revision,
+        hash: json_metadata.hash,
+    }
+    .emit()
+}
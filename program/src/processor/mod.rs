@@ -2,19 +2,60 @@ use crate::instruction::OnchainMetadataInstructions;
 use borsh::BorshDeserialize;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
+mod accept_authority;
+mod accounts;
 mod add_authority;
 mod append_value;
+mod attest;
+mod begin_upload;
+mod cancel_proposal;
+mod cancel_upload;
 mod close;
+mod commit_upload;
+mod delete_at_pointer;
+mod freeze;
 mod initialize;
+mod initialize_with_seed;
+mod migrate;
+mod patch_value;
+mod propose_authority;
 mod remove_authority;
+mod remove_schema;
+mod renounce_all;
+mod set_at_pointer;
+mod set_encoding;
+mod set_schema;
+mod set_threshold;
 mod set_value;
+mod verify_hash;
+mod write_chunk;
 
+use accept_authority::*;
 use add_authority::*;
 use append_value::*;
+use attest::*;
+use begin_upload::*;
+use cancel_proposal::*;
+use cancel_upload::*;
 use close::*;
+use commit_upload::*;
+use delete_at_pointer::*;
+use freeze::*;
 use initialize::*;
+use initialize_with_seed::*;
+use migrate::*;
+use patch_value::*;
+use propose_authority::*;
 use remove_authority::*;
+use remove_schema::*;
+use renounce_all::*;
+use set_at_pointer::*;
+use set_encoding::*;
+use set_schema::*;
+use set_threshold::*;
 use set_value::*;
+use verify_hash::*;
+use write_chunk::*;
 
 pub struct Processor;
 impl Processor {
@@ -50,6 +91,86 @@ impl Processor {
                 msg!("Instruction: RemoveAuthority");
                 process_remove_authority(accounts, args)
             }
+            OnchainMetadataInstructions::PatchValue(args) => {
+                msg!("Instruction: PatchValue");
+                process_patch_value(accounts, args)
+            }
+            OnchainMetadataInstructions::SetAtPointer(args) => {
+                msg!("Instruction: SetAtPointer");
+                process_set_at_pointer(accounts, args)
+            }
+            OnchainMetadataInstructions::DeleteAtPointer(args) => {
+                msg!("Instruction: DeleteAtPointer");
+                process_delete_at_pointer(accounts, args)
+            }
+            OnchainMetadataInstructions::Freeze => {
+                msg!("Instruction: Freeze");
+                process_freeze(accounts)
+            }
+            OnchainMetadataInstructions::BeginUpload(args) => {
+                msg!("Instruction: BeginUpload");
+                process_begin_upload(accounts, args)
+            }
+            OnchainMetadataInstructions::WriteChunk(args) => {
+                msg!("Instruction: WriteChunk");
+                process_write_chunk(accounts, args)
+            }
+            OnchainMetadataInstructions::CommitUpload(args) => {
+                msg!("Instruction: CommitUpload");
+                process_commit_upload(accounts, args)
+            }
+            OnchainMetadataInstructions::CancelUpload => {
+                msg!("Instruction: CancelUpload");
+                process_cancel_upload(accounts)
+            }
+            OnchainMetadataInstructions::SetSchema(args) => {
+                msg!("Instruction: SetSchema");
+                process_set_schema(accounts, args)
+            }
+            OnchainMetadataInstructions::RemoveSchema => {
+                msg!("Instruction: RemoveSchema");
+                process_remove_schema(accounts)
+            }
+            OnchainMetadataInstructions::InitializeWithSeed(args) => {
+                msg!("Instruction: InitializeWithSeed");
+                process_initialize_with_seed(accounts, args)
+            }
+            OnchainMetadataInstructions::Migrate => {
+                msg!("Instruction: Migrate");
+                process_migrate(accounts)
+            }
+            OnchainMetadataInstructions::RenounceAll => {
+                msg!("Instruction: RenounceAll");
+                process_renounce_all(accounts)
+            }
+            OnchainMetadataInstructions::ProposeAuthority(args) => {
+                msg!("Instruction: ProposeAuthority");
+                process_propose_authority(accounts, args)
+            }
+            OnchainMetadataInstructions::AcceptAuthority => {
+                msg!("Instruction: AcceptAuthority");
+                process_accept_authority(accounts)
+            }
+            OnchainMetadataInstructions::CancelProposal(args) => {
+                msg!("Instruction: CancelProposal");
+                process_cancel_proposal(accounts, args)
+            }
+            OnchainMetadataInstructions::SetThreshold(args) => {
+                msg!("Instruction: SetThreshold");
+                process_set_threshold(accounts, args)
+            }
+            OnchainMetadataInstructions::VerifyHash(args) => {
+                msg!("Instruction: VerifyHash");
+                process_verify_hash(accounts, args)
+            }
+            OnchainMetadataInstructions::SetEncoding(args) => {
+                msg!("Instruction: SetEncoding");
+                process_set_encoding(accounts, args)
+            }
+            OnchainMetadataInstructions::Attest => {
+                msg!("Instruction: Attest");
+                process_attest(accounts)
+            }
         }
     }
 }
@@ -0,0 +1,22 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{
+    error::OnchainMetadataError, event, instruction::PatchValueArgs, patch, state::ROLE_WRITER,
+};
+
+pub(crate) fn process_patch_value(accounts: &[AccountInfo], args: PatchValueArgs) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let writer = accounts.writer(ROLE_WRITER, args.expected_revision)?;
+
+    let mut json_data = accounts.value()?;
+
+    let patch_data: serde_json::Value =
+        serde_json::from_str(&args.patch).map_err(|_| OnchainMetadataError::InvalidJson)?;
+
+    // The patch is applied to the in-memory copy, so nothing is written if any operation fails.
+    patch::apply(&mut json_data, &patch_data)?;
+    let changed_paths = event::patch_paths(&patch_data);
+
+    accounts.write(&writer, &json_data, changed_paths)
+}
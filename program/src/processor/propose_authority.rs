@@ -0,0 +1,70 @@
+use borsh::BorshSerialize;
+use mpl_utils::resize_or_reallocate_account_raw;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{
+    event::JsonEvent,
+    instruction::ProposeAuthorityArgs,
+    state::{AuthorityEntry, ROLE_ADMIN},
+};
+
+pub(crate) fn process_propose_authority(
+    accounts: &[AccountInfo],
+    args: ProposeAuthorityArgs,
+) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let authority = accounts.authority(ROLE_ADMIN)?;
+    let payer = accounts.signer()?;
+    let system_program = accounts.system_program()?;
+
+    // Any further accounts are authorities co-signing to meet the threshold.
+    accounts.assert_threshold(authority, ROLE_ADMIN)?;
+
+    let JsonAccounts {
+        json_account,
+        json_metadata_account,
+        mut json_metadata,
+        ..
+    } = accounts;
+
+    // Propose the new authority, replacing any earlier proposal for the same key.
+    let entry = AuthorityEntry {
+        key: args.new_authority,
+        roles: args.roles,
+        paths: args.paths,
+    };
+    entry.check()?;
+    match json_metadata
+        .pending_authorities
+        .iter_mut()
+        .find(|existing| existing.key == entry.key)
+    {
+        Some(existing) => *existing = entry,
+        None => json_metadata.pending_authorities.push(entry),
+    }
+
+    json_metadata.record_change()?;
+
+    // Write the updated JSON metadata account back to the account.
+    let serialized_data = json_metadata.try_to_vec()?;
+
+    // Resize the account to fit the proposal.
+    resize_or_reallocate_account_raw(
+        json_metadata_account,
+        payer,
+        system_program,
+        serialized_data.len(),
+    )?;
+
+    // Write the JSON metadata to the JSON metadata account.
+    sol_memcpy(
+        &mut json_metadata_account.try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
+    JsonEvent::proposals_changed(json_account.key, &json_metadata).emit()
+}
@@ -1,69 +1,47 @@
-use borsh::{BorshDeserialize, BorshSerialize};
-use mpl_utils::{assert_derivation, assert_signer, resize_or_reallocate_account_raw};
+use borsh::BorshSerialize;
+use mpl_utils::resize_or_reallocate_account_raw;
 use solana_program::{
-    account_info::{next_account_info, AccountInfo},
-    entrypoint::ProgramResult,
-    program_memory::sol_memcpy,
-    system_program,
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
 };
 
+use super::accounts::{Access, JsonAccounts};
 use crate::{
-    error::OnchainMetadataError, instruction::RemoveAuthorityArgs, pda::PREFIX, state::JsonMetadata,
+    error::OnchainMetadataError, event::JsonEvent, instruction::RemoveAuthorityArgs,
+    state::ROLE_ADMIN,
 };
 
 pub(crate) fn process_remove_authority(
     accounts: &[AccountInfo],
     args: RemoveAuthorityArgs,
 ) -> ProgramResult {
-    let account_info_iter = &mut accounts.iter();
-    let json_account = next_account_info(account_info_iter)?;
-    // Check that the account isn't already initialized.
-    if (json_account.owner != &crate::ID) || json_account.data_is_empty() {
-        return Err(OnchainMetadataError::NotInitialized.into());
-    }
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let authority = accounts.authority(ROLE_ADMIN)?;
+    let payer = accounts.signer()?;
+    let system_program = accounts.system_program()?;
 
-    let json_metadata_account = next_account_info(account_info_iter)?;
-    // Check that the account isn't already initialized.
-    if (json_metadata_account.owner != &crate::ID) || json_metadata_account.data_is_empty() {
-        return Err(OnchainMetadataError::NotInitialized.into());
-    }
-    let mut json_metadata = JsonMetadata::try_from_slice(&json_metadata_account.data.borrow())?;
+    // Any further accounts are authorities co-signing to meet the threshold.
+    accounts.assert_threshold(authority, ROLE_ADMIN)?;
 
-    // Verify that the derived address is correct for the JSON metadata account.
-    let bump = assert_derivation(
-        &crate::ID,
+    let JsonAccounts {
+        json_account,
         json_metadata_account,
-        &[
-            PREFIX.as_bytes(),
-            crate::ID.as_ref(),
-            json_account.key.as_ref(),
-        ],
-        OnchainMetadataError::MetadataDerivedKeyInvalid,
-    )?;
-    if bump != json_metadata.bump {
-        return Err(OnchainMetadataError::MetadataDerivedKeyInvalid.into());
-    }
-
-    let payer = next_account_info(account_info_iter)?;
-    // The payer and authority must sign.
-    assert_signer(payer)?;
-    if !json_metadata.authorities.contains(payer.key) {
-        return Err(OnchainMetadataError::InvalidAuthority.into());
-    }
-
-    let system_program = next_account_info(account_info_iter)?;
-    if system_program.key != &system_program::ID {
-        return Err(OnchainMetadataError::InvalidSystemProgram.into());
-    }
+        mut json_metadata,
+        ..
+    } = accounts;
 
-    // Add the new authority.
+    // Remove the authority.
     let index = json_metadata
         .authorities
         .iter()
-        .position(|x| x == &args.authority)
+        .position(|entry| entry.key == args.authority)
         .ok_or(OnchainMetadataError::InvalidAuthority)?;
     json_metadata.authorities.swap_remove(index);
 
+    // Leaving no admin would orphan the account, which must be done through RenounceAll.
+    json_metadata.assert_authorities()?;
+
+    json_metadata.record_change()?;
+
     // Write the updated JSON metadata account back to the account.
     let serialized_data = json_metadata.try_to_vec()?;
 
@@ -82,5 +60,5 @@ pub(crate) fn process_remove_authority(
         serialized_data.len(),
     );
 
-    Ok(())
+    JsonEvent::authorities_changed(json_account.key, &json_metadata).emit()
 }
@@ -0,0 +1,63 @@
+use borsh::BorshSerialize;
+use mpl_utils::{close_account_raw, resize_or_reallocate_account_raw};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{error::OnchainMetadataError, event::JsonEvent, state::ROLE_ADMIN};
+
+pub(crate) fn process_remove_schema(accounts: &[AccountInfo]) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let schema_account = accounts.next()?;
+    if accounts.json_metadata.schema != Some(*schema_account.key) {
+        return Err(OnchainMetadataError::SchemaDerivedKeyInvalid.into());
+    }
+
+    let authority = accounts.authority(ROLE_ADMIN)?;
+    let payer = accounts.signer()?;
+    let rent_destination = accounts.next()?;
+    let system_program = accounts.system_program()?;
+
+    // Any further accounts are authorities co-signing to meet the threshold.
+    accounts.assert_threshold(authority, ROLE_ADMIN)?;
+
+    let JsonAccounts {
+        json_account,
+        json_metadata_account,
+        mut json_metadata,
+        ..
+    } = accounts;
+
+    // Detach the schema from the metadata.
+    json_metadata.schema = None;
+
+    json_metadata.record_change()?;
+
+    // Write the updated JSON metadata account back to the account.
+    let serialized_metadata = json_metadata.try_to_vec()?;
+
+    // Resize the account to drop the schema reference.
+    resize_or_reallocate_account_raw(
+        json_metadata_account,
+        payer,
+        system_program,
+        serialized_metadata.len(),
+    )?;
+
+    // Write the JSON metadata to the JSON metadata account.
+    sol_memcpy(
+        &mut json_metadata_account.try_borrow_mut_data()?,
+        &serialized_metadata,
+        serialized_metadata.len(),
+    );
+
+    // Close the schema account, releasing its rent.
+    close_account_raw(rent_destination, schema_account)?;
+
+    JsonEvent::SchemaChanged {
+        json_account: *json_account.key,
+        schema: None,
+    }
+    .emit()
+}
@@ -0,0 +1,57 @@
+use borsh::BorshSerialize;
+use mpl_utils::resize_or_reallocate_account_raw;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{event::JsonEvent, state::ROLE_ADMIN};
+
+pub(crate) fn process_renounce_all(accounts: &[AccountInfo]) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let authority = accounts.authority(ROLE_ADMIN)?;
+    let payer = accounts.signer()?;
+    let system_program = accounts.system_program()?;
+
+    // Any further accounts are authorities co-signing to meet the threshold.
+    accounts.assert_threshold(authority, ROLE_ADMIN)?;
+
+    let JsonAccounts {
+        json_account,
+        json_metadata_account,
+        mut json_metadata,
+        ..
+    } = accounts;
+
+    // Nobody can modify the account afterwards, so make that explicit by freezing it. Pending
+    // proposals could never be accepted or cancelled, so they're dropped too.
+    json_metadata.authorities.clear();
+    let had_proposals = !json_metadata.pending_authorities.is_empty();
+    json_metadata.pending_authorities.clear();
+    json_metadata.mutable = false;
+
+    json_metadata.record_change()?;
+
+    // Write the updated JSON metadata account back to the account.
+    let serialized_data = json_metadata.try_to_vec()?;
+
+    // Shrink the account now that it holds no authorities or proposals.
+    resize_or_reallocate_account_raw(
+        json_metadata_account,
+        payer,
+        system_program,
+        serialized_data.len(),
+    )?;
+
+    // Write the JSON metadata to the JSON metadata account.
+    sol_memcpy(
+        &mut json_metadata_account.try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
+    if had_proposals {
+        JsonEvent::proposals_changed(json_account.key, &json_metadata).emit()?;
+    }
+    JsonEvent::authorities_changed(json_account.key, &json_metadata).emit()
+}
@@ -0,0 +1,24 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{
+    error::OnchainMetadataError, instruction::SetAtPointerArgs, pointer, state::ROLE_WRITER,
+};
+
+pub(crate) fn process_set_at_pointer(
+    accounts: &[AccountInfo],
+    args: SetAtPointerArgs,
+) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let writer = accounts.writer(ROLE_WRITER, args.expected_revision)?;
+
+    let mut json_data = accounts.value()?;
+
+    let path = pointer::parse(&args.pointer)?;
+    let new_data: serde_json::Value =
+        serde_json::from_str(&args.value).map_err(|_| OnchainMetadataError::InvalidJson)?;
+
+    pointer::set(&mut json_data, &path, new_data)?;
+
+    accounts.write(&writer, &json_data, vec![args.pointer])
+}
@@ -0,0 +1,28 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use super::accounts::{Access, JsonAccounts, Writer};
+use crate::{instruction::SetEncodingArgs, state::ROLE_ADMIN};
+
+pub(crate) fn process_set_encoding(
+    accounts: &[AccountInfo],
+    args: SetEncodingArgs,
+) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let authority = accounts.authority(ROLE_ADMIN)?;
+    let writer = Writer {
+        authority,
+        payer: accounts.signer()?,
+        rent_destination: accounts.next()?,
+        system_program: accounts.system_program()?,
+        schema_account: None,
+    };
+
+    // Any further accounts are authorities co-signing to meet the threshold.
+    accounts.assert_threshold(authority, ROLE_ADMIN)?;
+
+    // Re-encode the existing document right away so the stored bytes always match the selected
+    // encoding. The document itself doesn't change, so neither does its hash.
+    let json_data = accounts.value()?;
+    accounts.json_metadata.encoding = args.encoding;
+    accounts.store(&writer, &json_data, Vec::new())
+}
@@ -0,0 +1,115 @@
+use borsh::BorshSerialize;
+use mpl_utils::{
+    assert_derivation, create_or_allocate_account_raw, resize_or_reallocate_account_raw,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{
+    error::OnchainMetadataError,
+    event::JsonEvent,
+    instruction::SetSchemaArgs,
+    pda::{PREFIX, SCHEMA},
+    schema,
+    state::ROLE_ADMIN,
+};
+
+pub(crate) fn process_set_schema(accounts: &[AccountInfo], args: SetSchemaArgs) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let schema_account = accounts.next()?;
+    // Verify that the derived address is correct for the schema account.
+    let schema_bump = assert_derivation(
+        &crate::ID,
+        schema_account,
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            accounts.json_account.key.as_ref(),
+            SCHEMA.as_bytes(),
+        ],
+        OnchainMetadataError::SchemaDerivedKeyInvalid,
+    )?;
+
+    let authority = accounts.authority(ROLE_ADMIN)?;
+    let payer = accounts.signer()?;
+    let system_program = accounts.system_program()?;
+
+    // Any further accounts are authorities co-signing to meet the threshold.
+    accounts.assert_threshold(authority, ROLE_ADMIN)?;
+
+    let JsonAccounts {
+        json_account,
+        json_metadata_account,
+        mut json_metadata,
+        ..
+    } = accounts;
+
+    // Only the supported subset of JSON Schema can be attached.
+    let schema_data: serde_json::Value =
+        serde_json::from_str(&args.schema).map_err(|_| OnchainMetadataError::InvalidSchema)?;
+    schema::check(&schema_data)?;
+    let serialized_schema =
+        serde_json::to_vec(&schema_data).map_err(|_| OnchainMetadataError::InvalidSchema)?;
+
+    if schema_account.data_is_empty() {
+        solana_program::msg!("Creating schema account");
+        create_or_allocate_account_raw(
+            crate::ID,
+            schema_account,
+            system_program,
+            payer,
+            serialized_schema.len(),
+            &[
+                PREFIX.as_bytes(),
+                crate::ID.as_ref(),
+                json_account.key.as_ref(),
+                SCHEMA.as_bytes(),
+                &[schema_bump],
+            ],
+        )?;
+    } else {
+        resize_or_reallocate_account_raw(
+            schema_account,
+            payer,
+            system_program,
+            serialized_schema.len(),
+        )?;
+    }
+
+    // Write the schema to the schema account.
+    sol_memcpy(
+        &mut schema_account.try_borrow_mut_data()?,
+        &serialized_schema,
+        serialized_schema.len(),
+    );
+
+    json_metadata.schema = Some(*schema_account.key);
+
+    json_metadata.record_change()?;
+
+    // Write the updated JSON metadata account back to the account.
+    let serialized_metadata = json_metadata.try_to_vec()?;
+
+    // Resize the account to fit the schema reference.
+    resize_or_reallocate_account_raw(
+        json_metadata_account,
+        payer,
+        system_program,
+        serialized_metadata.len(),
+    )?;
+
+    // Write the JSON metadata to the JSON metadata account.
+    sol_memcpy(
+        &mut json_metadata_account.try_borrow_mut_data()?,
+        &serialized_metadata,
+        serialized_metadata.len(),
+    );
+
+    JsonEvent::SchemaChanged {
+        json_account: *json_account.key,
+        schema: json_metadata.schema,
+    }
+    .emit()
+}
@@ -0,0 +1,47 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memcpy,
+};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{
+    error::OnchainMetadataError, event::JsonEvent, instruction::SetThresholdArgs, state::ROLE_ADMIN,
+};
+
+pub(crate) fn process_set_threshold(
+    accounts: &[AccountInfo],
+    args: SetThresholdArgs,
+) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let authority = accounts.authority(ROLE_ADMIN)?;
+
+    // Any further accounts are authorities co-signing to meet the threshold.
+    accounts.assert_threshold(authority, ROLE_ADMIN)?;
+
+    let JsonAccounts {
+        json_account,
+        json_metadata_account,
+        mut json_metadata,
+        ..
+    } = accounts;
+
+    // The new threshold must be reachable by the current authorities.
+    if args.threshold == 0 {
+        return Err(OnchainMetadataError::InvalidThreshold.into());
+    }
+    json_metadata.threshold = args.threshold;
+    json_metadata.assert_authorities()?;
+
+    json_metadata.record_change()?;
+
+    // Write the updated JSON metadata account back to the account. The size doesn't change so
+    // no reallocation is needed.
+    let serialized_data = json_metadata.try_to_vec()?;
+    sol_memcpy(
+        &mut json_metadata_account.try_borrow_mut_data()?,
+        &serialized_data,
+        serialized_data.len(),
+    );
+
+    JsonEvent::authorities_changed(json_account.key, &json_metadata).emit()
+}
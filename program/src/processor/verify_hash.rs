@@ -0,0 +1,18 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use super::accounts::{Access, JsonAccounts};
+use crate::{error::OnchainMetadataError, instruction::VerifyHashArgs};
+
+pub(crate) fn process_verify_hash(accounts: &[AccountInfo], args: VerifyHashArgs) -> ProgramResult {
+    // Loading checks the metadata address, so callers can't be handed metadata belonging to a
+    // different JSON account.
+    let accounts = JsonAccounts::load(accounts, Access::Read)?;
+    let json_metadata = accounts.json_metadata;
+
+    // The stored hash is kept up to date by every write, so the JSON data needn't be rehashed.
+    if json_metadata.hash != args.expected {
+        return Err(OnchainMetadataError::HashMismatch.into());
+    }
+
+    Ok(())
+}
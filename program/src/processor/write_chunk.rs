@@ -0,0 +1,31 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use super::accounts::{assert_uploader, Access, JsonAccounts};
+use crate::{
+    error::OnchainMetadataError,
+    instruction::WriteChunkArgs,
+    state::{BUFFER_HEADER_LEN, ROLE_WRITER},
+};
+
+pub(crate) fn process_write_chunk(accounts: &[AccountInfo], args: WriteChunkArgs) -> ProgramResult {
+    let mut accounts = JsonAccounts::load(accounts, Access::Write)?;
+    let buffer_account = accounts.buffer_account()?;
+    let authority = accounts.authority(ROLE_WRITER)?;
+    assert_uploader(buffer_account, authority)?;
+
+    // Any further accounts are authorities co-signing to meet the threshold.
+    accounts.assert_threshold(authority, ROLE_WRITER)?;
+
+    // Copy the chunk into the document at the requested offset, past the header.
+    let mut buffer_data = buffer_account.try_borrow_mut_data()?;
+    let start = BUFFER_HEADER_LEN + args.offset as usize;
+    let end = start
+        .checked_add(args.bytes.len())
+        .ok_or(OnchainMetadataError::ChunkOutOfBounds)?;
+    buffer_data
+        .get_mut(start..end)
+        .ok_or(OnchainMetadataError::ChunkOutOfBounds)?
+        .copy_from_slice(&args.bytes);
+
+    Ok(())
+}
@@ -0,0 +1,271 @@
+//! Reading single values from the raw bytes of a JSON account without parsing the document.
+//!
+//! Programs that only need one field can resolve a JSON Pointer with [`get`] or [`get_as`]
+//! instead of building a `serde_json::Value` for the whole document. Only the members and
+//! elements on the way to the value are looked at; everything else is skipped over bracket by
+//! bracket. The data must use the `Json` or `CanonicalJson` encoding, which this program always
+//! writes as valid JSON, so the skipped parts aren't validated.
+//!
+//! Accounts passed in by a caller should go through [`load`] first, which checks that they
+//! belong to this program and hold JSON text.
+
+use std::cell::Ref;
+
+use serde::Deserialize;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::OnchainMetadataError,
+    pda::PREFIX,
+    pointer,
+    state::{Encoding, JsonMetadata},
+};
+
+/// Checks `json_account` and its metadata account, returning the JSON data to read from.
+///
+/// Both accounts must be owned by this program, the metadata account must be at the address
+/// derived from the JSON account, and the data must be stored as JSON text.
+pub fn load<'a, 'b>(
+    json_account: &'a AccountInfo<'b>,
+    json_metadata_account: &AccountInfo,
+) -> Result<Ref<'a, &'b mut [u8]>, ProgramError> {
+    if json_account.owner != &crate::ID || json_metadata_account.owner != &crate::ID {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let json_metadata = JsonMetadata::load(&json_metadata_account.data.borrow())?;
+    let metadata_key = Pubkey::create_program_address(
+        &[
+            PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            json_account.key.as_ref(),
+            &[json_metadata.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| OnchainMetadataError::MetadataDerivedKeyInvalid)?;
+    if json_metadata_account.key != &metadata_key {
+        return Err(OnchainMetadataError::MetadataDerivedKeyInvalid.into());
+    }
+    check_encoding(&json_metadata)?;
+
+    Ok(json_account.data.borrow())
+}
+
+/// Checks that the JSON account described by `json_metadata` stores JSON text, which is all this
+/// module can read.
+pub fn check_encoding(json_metadata: &JsonMetadata) -> Result<(), OnchainMetadataError> {
+    match json_metadata.encoding {
+        Encoding::Json | Encoding::CanonicalJson => Ok(()),
+        Encoding::MessagePack => Err(OnchainMetadataError::UnsupportedEncoding),
+    }
+}
+
+/// Returns the JSON text of the value referenced by the RFC 6901 `pointer`.
+pub fn get<'a>(data: &'a [u8], pointer: &str) -> Result<&'a [u8], OnchainMetadataError> {
+    let tokens = match pointer {
+        "" => None,
+        _ => Some(
+            pointer
+                .strip_prefix('/')
+                .ok_or(OnchainMetadataError::InvalidPointer)?
+                .split('/'),
+        ),
+    };
+
+    let mut scanner = Scanner { data, pos: 0 };
+    scanner.skip_whitespace();
+    for token in tokens.into_iter().flatten() {
+        check_token(token)?;
+        match scanner.peek() {
+            Some(b'{') => scanner.find_member(token)?,
+            Some(b'[') => {
+                let index =
+                    pointer::array_index(token).ok_or(OnchainMetadataError::PointerNotFound)?;
+                scanner.find_element(index)?
+            }
+            Some(_) => return Err(OnchainMetadataError::PointerNotFound),
+            None => return Err(OnchainMetadataError::InvalidJson),
+        }
+    }
+
+    let start = scanner.pos;
+    scanner.skip_value()?;
+    Ok(&data[start..scanner.pos])
+}
+
+/// Deserializes the value referenced by `pointer` into `T`, which is typically a scalar such as
+/// `u64`, `bool` or `&str`. Borrowed strings only work when the stored string has no escapes.
+pub fn get_as<'a, T: Deserialize<'a>>(
+    data: &'a [u8],
+    pointer: &str,
+) -> Result<T, OnchainMetadataError> {
+    serde_json::from_slice(get(data, pointer)?).map_err(|_| OnchainMetadataError::TypeMismatch)
+}
+
+/// Rejects tokens with a `~` that isn't part of a `~0` or `~1` escape.
+fn check_token(token: &str) -> Result<(), OnchainMetadataError> {
+    let mut bytes = token.bytes();
+    while let Some(b) = bytes.next() {
+        if b == b'~' && !matches!(bytes.next(), Some(b'0' | b'1')) {
+            return Err(OnchainMetadataError::InvalidPointer);
+        }
+    }
+    Ok(())
+}
+
+/// Checks whether the quoted JSON string `key` names the member referenced by `token`.
+fn key_matches(key: &[u8], token: &str) -> bool {
+    let raw = &key[1..key.len() - 1];
+    if !raw.contains(&b'\\') && !token.contains('~') {
+        return raw == token.as_bytes();
+    }
+
+    // Escapes are rare enough in member names that unescaping them here is cheap overall.
+    match serde_json::from_slice::<String>(key) {
+        Ok(key) => key == token.replace("~1", "/").replace("~0", "~"),
+        Err(_) => false,
+    }
+}
+
+struct Scanner<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), OnchainMetadataError> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            return Err(OnchainMetadataError::InvalidJson);
+        }
+        self.pos += 1;
+        self.skip_whitespace();
+        Ok(())
+    }
+
+    /// Moves past the `,` between two items, returning `false` at the `close` bracket instead.
+    fn next_item(&mut self, close: u8) -> Result<bool, OnchainMetadataError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b',') => {
+                self.pos += 1;
+                self.skip_whitespace();
+                Ok(true)
+            }
+            Some(b) if b == close => Ok(false),
+            _ => Err(OnchainMetadataError::InvalidJson),
+        }
+    }
+
+    /// Moves to the value of the member named by `token` in the object at the current position.
+    fn find_member(&mut self, token: &str) -> Result<(), OnchainMetadataError> {
+        self.expect(b'{')?;
+        if self.peek() == Some(b'}') {
+            return Err(OnchainMetadataError::PointerNotFound);
+        }
+
+        loop {
+            let key = self.string()?;
+            self.expect(b':')?;
+            if key_matches(key, token) {
+                return Ok(());
+            }
+            self.skip_value()?;
+            if !self.next_item(b'}')? {
+                return Err(OnchainMetadataError::PointerNotFound);
+            }
+        }
+    }
+
+    /// Moves to the element at `index` in the array at the current position.
+    fn find_element(&mut self, index: usize) -> Result<(), OnchainMetadataError> {
+        self.expect(b'[')?;
+        if self.peek() == Some(b']') {
+            return Err(OnchainMetadataError::PointerNotFound);
+        }
+
+        for _ in 0..index {
+            self.skip_value()?;
+            if !self.next_item(b']')? {
+                return Err(OnchainMetadataError::PointerNotFound);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the string at the current position, including its quotes.
+    fn string(&mut self) -> Result<&'a [u8], OnchainMetadataError> {
+        let start = self.pos;
+        if self.peek() != Some(b'"') {
+            return Err(OnchainMetadataError::InvalidJson);
+        }
+        self.pos += 1;
+
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(&self.data[start..self.pos]);
+                }
+                Some(b'\\') => self.pos += 2,
+                Some(_) => self.pos += 1,
+                None => return Err(OnchainMetadataError::InvalidJson),
+            }
+        }
+    }
+
+    /// Moves past the value at the current position.
+    fn skip_value(&mut self) -> Result<(), OnchainMetadataError> {
+        match self.peek() {
+            Some(b'"') => self.string().map(|_| ()),
+            Some(b'{' | b'[') => {
+                let mut depth = 0usize;
+                loop {
+                    match self.peek() {
+                        Some(b'"') => {
+                            self.string()?;
+                            continue;
+                        }
+                        Some(b'{' | b'[') => depth += 1,
+                        Some(b'}' | b']') => {
+                            depth -= 1;
+                            if depth == 0 {
+                                self.pos += 1;
+                                return Ok(());
+                            }
+                        }
+                        Some(_) => {}
+                        None => return Err(OnchainMetadataError::InvalidJson),
+                    }
+                    self.pos += 1;
+                }
+            }
+            Some(_) => {
+                // Numbers and the `true`, `false` and `null` literals run until the next delimiter.
+                let start = self.pos;
+                while !matches!(
+                    self.peek(),
+                    None | Some(b',' | b'}' | b']' | b' ' | b'\t' | b'\n' | b'\r')
+                ) {
+                    self.pos += 1;
+                }
+                if self.pos == start {
+                    return Err(OnchainMetadataError::InvalidJson);
+                }
+                Ok(())
+            }
+            None => Err(OnchainMetadataError::InvalidJson),
+        }
+    }
+}
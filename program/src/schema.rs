@@ -0,0 +1,153 @@
+use serde_json::{Map, Value};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use crate::error::OnchainMetadataError;
+
+const TYPES: [&str; 7] = [
+    "object", "array", "string", "number", "integer", "boolean", "null",
+];
+
+/// Checks that `schema` only uses the supported subset of JSON Schema in a well-formed way.
+///
+/// The supported keywords are `type`, `required`, `properties`, `items`, `enum` and `maxLength`.
+/// Other keywords are ignored, as JSON Schema requires for unknown keywords.
+pub fn check(schema: &Value) -> Result<(), OnchainMetadataError> {
+    let schema = match schema {
+        Value::Bool(_) => return Ok(()),
+        Value::Object(schema) => schema,
+        _ => return Err(OnchainMetadataError::InvalidSchema),
+    };
+
+    if let Some(types) = schema.get("type") {
+        let valid = match types {
+            Value::String(name) => TYPES.contains(&name.as_str()),
+            Value::Array(names) => names
+                .iter()
+                .all(|name| name.as_str().map_or(false, |name| TYPES.contains(&name))),
+            _ => false,
+        };
+        if !valid {
+            return Err(OnchainMetadataError::InvalidSchema);
+        }
+    }
+    if let Some(required) = schema.get("required") {
+        if !required
+            .as_array()
+            .map_or(false, |keys| keys.iter().all(Value::is_string))
+        {
+            return Err(OnchainMetadataError::InvalidSchema);
+        }
+    }
+    if let Some(properties) = schema.get("properties") {
+        properties
+            .as_object()
+            .ok_or(OnchainMetadataError::InvalidSchema)?
+            .values()
+            .try_for_each(check)?;
+    }
+    if let Some(items) = schema.get("items") {
+        check(items)?;
+    }
+    if let Some(values) = schema.get("enum") {
+        if !values.is_array() {
+            return Err(OnchainMetadataError::InvalidSchema);
+        }
+    }
+    if let Some(max_length) = schema.get("maxLength") {
+        if !max_length.is_u64() {
+            return Err(OnchainMetadataError::InvalidSchema);
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates `doc` against `schema`, failing with `SchemaViolation` if it doesn't conform.
+pub fn validate(schema: &Value, doc: &Value) -> Result<(), OnchainMetadataError> {
+    match schema {
+        Value::Bool(true) => Ok(()),
+        Value::Object(schema) => validate_object(schema, doc),
+        _ => Err(OnchainMetadataError::SchemaViolation),
+    }
+}
+
+/// Validates `doc` against the schema stored in `schema_account`.
+pub(crate) fn validate_account(schema_account: &AccountInfo, doc: &Value) -> ProgramResult {
+    let schema: Value = serde_json::from_slice(&schema_account.data.borrow())
+        .map_err(|_| OnchainMetadataError::InvalidSchema)?;
+    validate(&schema, doc)?;
+    Ok(())
+}
+
+fn validate_object(schema: &Map<String, Value>, doc: &Value) -> Result<(), OnchainMetadataError> {
+    if let Some(types) = schema.get("type") {
+        let matches = match types {
+            Value::String(name) => is_type(name, doc),
+            Value::Array(names) => names
+                .iter()
+                .any(|name| name.as_str().map_or(false, |name| is_type(name, doc))),
+            _ => false,
+        };
+        if !matches {
+            return Err(OnchainMetadataError::SchemaViolation);
+        }
+    }
+
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        if !values.contains(doc) {
+            return Err(OnchainMetadataError::SchemaViolation);
+        }
+    }
+
+    match doc {
+        Value::Object(map) => {
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                let missing = required
+                    .iter()
+                    .any(|key| key.as_str().map_or(true, |key| !map.contains_key(key)));
+                if missing {
+                    return Err(OnchainMetadataError::SchemaViolation);
+                }
+            }
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (key, property_schema) in properties {
+                    if let Some(value) = map.get(key) {
+                        validate(property_schema, value)?;
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                items
+                    .iter()
+                    .try_for_each(|item| validate(item_schema, item))?;
+            }
+        }
+        Value::String(string) => {
+            if let Some(max_length) = schema.get("maxLength").and_then(Value::as_u64) {
+                if string.chars().count() as u64 > max_length {
+                    return Err(OnchainMetadataError::SchemaViolation);
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn is_type(name: &str, doc: &Value) -> bool {
+    match name {
+        "object" => doc.is_object(),
+        "array" => doc.is_array(),
+        "string" => doc.is_string(),
+        "number" => doc.is_number(),
+        "integer" => {
+            doc.is_i64() || doc.is_u64() || doc.as_f64().map_or(false, |n| n.fract() == 0.0)
+        }
+        "boolean" => doc.is_boolean(),
+        "null" => doc.is_null(),
+        _ => false,
+    }
+}
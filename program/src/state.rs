@@ -1,11 +1,351 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use serde_json::Value;
 use shank::ShankAccount;
-use solana_program::pubkey::Pubkey;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{canonical, error::OnchainMetadataError, pointer};
+
+/// The current layout version of `JsonMetadata`.
+///
+/// The version is stored at byte 1, where the legacy layout kept its `mutable` flag. Legacy
+/// accounts therefore always have a 0 or 1 there and can be told apart from current ones.
+pub const METADATA_VERSION: u8 = 2;
+
+/// Allows managing authorities, closing, freezing and attaching schemas.
+pub const ROLE_ADMIN: u8 = 1 << 0;
+/// Allows every change to the JSON data.
+pub const ROLE_WRITER: u8 = 1 << 1;
+/// Allows `AppendValue` only.
+pub const ROLE_APPENDER: u8 = 1 << 2;
+/// Every role.
+pub const ROLE_ALL: u8 = ROLE_ADMIN | ROLE_WRITER | ROLE_APPENDER;
+
+/// The length of the upload buffer's header, which holds the key of the authority that began
+/// the upload. The document being uploaded follows it.
+pub const BUFFER_HEADER_LEN: usize = 32;
+
+/// How the JSON data is serialized when it is written to the JSON account.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    /// `serde_json`'s compact output.
+    Json,
+    /// RFC 8785 canonical JSON, whose bytes are reproducible by any conforming implementation.
+    /// Integers larger than 2^53 can't be stored in it.
+    CanonicalJson,
+    /// MessagePack, a binary form of the same data model that needs less space and rent.
+    MessagePack,
+}
+
+impl Encoding {
+    /// Serializes `value` for storage in the JSON account.
+    pub fn serialize(&self, value: &Value) -> Result<Vec<u8>, ProgramError> {
+        match self {
+            Encoding::Json => {
+                serde_json::to_vec(value).map_err(|_| OnchainMetadataError::InvalidJson.into())
+            }
+            Encoding::CanonicalJson => canonical::to_vec(value),
+            Encoding::MessagePack => {
+                rmp_serde::to_vec(value).map_err(|_| OnchainMetadataError::InvalidJson.into())
+            }
+        }
+    }
+
+    /// Deserializes the data stored in the JSON account.
+    pub fn deserialize(&self, data: &[u8]) -> Result<Value, ProgramError> {
+        match self {
+            Encoding::Json | Encoding::CanonicalJson => {
+                serde_json::from_slice(data).map_err(|_| OnchainMetadataError::InvalidJson.into())
+            }
+            Encoding::MessagePack => {
+                rmp_serde::from_slice(data).map_err(|_| OnchainMetadataError::InvalidJson.into())
+            }
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct AuthorityEntry {
+    pub key: Pubkey,
+    /// A bitmask of the `ROLE_*` constants granted to `key`.
+    pub roles: u8,
+    /// JSON Pointer prefixes limiting where `key` may change the JSON data. Empty means anywhere.
+    pub paths: Vec<String>,
+}
+
+impl AuthorityEntry {
+    /// Checks that the roles are known and non-empty and that every path is a valid JSON Pointer.
+    pub fn check(&self) -> ProgramResult {
+        if self.roles == 0 || self.roles & !ROLE_ALL != 0 {
+            return Err(OnchainMetadataError::InvalidRoles.into());
+        }
+        for path in &self.paths {
+            pointer::parse(path)?;
+        }
+
+        Ok(())
+    }
+}
 
 #[repr(C)]
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
 pub struct JsonMetadata {
+    pub bump: u8,
+    /// The layout version, always `METADATA_VERSION` for accounts written by this program.
+    pub version: u8,
+    pub mutable: bool,
+    pub authorities: Vec<AuthorityEntry>,
+    /// How many authorities holding the required role must sign each authority-checked instruction.
+    pub threshold: u8,
+    /// The schema account that the JSON data must conform to, if any.
+    pub schema: Option<Pubkey>,
+    /// Authorities proposed by an admin that take effect once they accept.
+    pub pending_authorities: Vec<AuthorityEntry>,
+    /// How many times the JSON data or metadata has changed, starting from 0 for a new account.
+    pub revision: u64,
+    /// The slot of the latest change, or 0 if the account has never changed.
+    pub last_updated_slot: u64,
+    /// The SHA-256 hash of the JSON data in RFC 8785 canonical form, whatever its encoding.
+    pub hash: [u8; 32],
+    /// How the JSON data is serialized on every write.
+    pub encoding: Encoding,
+}
+
+impl JsonMetadata {
+    /// Creates the metadata for a new JSON account, giving `authority` every role.
+    pub fn new(bump: u8, authority: Pubkey) -> Self {
+        Self {
+            bump,
+            version: METADATA_VERSION,
+            mutable: true,
+            authorities: vec![AuthorityEntry {
+                key: authority,
+                roles: ROLE_ALL,
+                paths: Vec::new(),
+            }],
+            threshold: 1,
+            schema: None,
+            pending_authorities: Vec::new(),
+            revision: 0,
+            last_updated_slot: 0,
+            hash: [0; 32],
+            encoding: Encoding::Json,
+        }
+    }
+
+    /// Deserializes the metadata, failing with `MetadataNeedsMigration` for legacy layouts.
+    pub fn load(data: &[u8]) -> Result<Self, ProgramError> {
+        match data.get(1) {
+            Some(&METADATA_VERSION) => Ok(Self::try_from_slice(data)?),
+            Some(0) | Some(1) => Err(OnchainMetadataError::MetadataNeedsMigration.into()),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    /// Returns whether `key` holds at least one of the roles in `roles`.
+    pub fn has_role(&self, key: &Pubkey, roles: u8) -> bool {
+        self.authorities
+            .iter()
+            .any(|entry| &entry.key == key && entry.roles & roles != 0)
+    }
+
+    /// Adds `entry` to `authorities`, replacing the roles and paths of an existing authority.
+    pub fn set_authority(&mut self, entry: AuthorityEntry) {
+        match self
+            .authorities
+            .iter_mut()
+            .find(|existing| existing.key == entry.key)
+        {
+            Some(existing) => *existing = entry,
+            None => self.authorities.push(entry),
+        }
+    }
+
+    /// Returns how many authorities can manage the account.
+    pub fn admin_count(&self) -> usize {
+        self.authorities
+            .iter()
+            .filter(|entry| entry.roles & ROLE_ADMIN != 0)
+            .count()
+    }
+
+    /// Returns how many distinct authorities holding one of `roles` can approve a change together.
+    ///
+    /// Authorities limited to some paths only count as cosigners when unrestricted, so at most one
+    /// of them, the one making the change, counts.
+    pub fn approver_count(&self, roles: u8) -> usize {
+        let holders = self
+            .authorities
+            .iter()
+            .filter(|entry| entry.roles & roles != 0);
+        let unrestricted = holders.clone().filter(|entry| entry.paths.is_empty()).count();
+        let restricted = holders.count() > unrestricted;
+
+        unrestricted + restricted as usize
+    }
+
+    /// Checks that the account keeps an admin and that the threshold stays reachable.
+    ///
+    /// The threshold applies to writes as much as to admin changes, so the admins, and the
+    /// writers and appenders if there are any, must each be able to meet it.
+    pub fn assert_authorities(&self) -> ProgramResult {
+        if self.admin_count() == 0 {
+            return Err(OnchainMetadataError::LastAuthority.into());
+        }
+        for roles in [ROLE_ADMIN, ROLE_WRITER, ROLE_WRITER | ROLE_APPENDER] {
+            let approvers = self.approver_count(roles);
+            if roles != ROLE_ADMIN && approvers == 0 {
+                continue;
+            }
+            if approvers < self.threshold as usize {
+                return Err(OnchainMetadataError::InvalidThreshold.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `authority` and the signers among `cosigners` are at least `threshold` distinct
+    /// authorities holding one of `roles`.
+    ///
+    /// Cosigners limited to some paths only count when unrestricted, since their approval says
+    /// nothing about changes elsewhere in the document.
+    pub fn assert_threshold(
+        &self,
+        authority: &AccountInfo,
+        cosigners: &[AccountInfo],
+        roles: u8,
+    ) -> ProgramResult {
+        let mut approvals = vec![authority.key];
+        for cosigner in cosigners {
+            let unrestricted = self.authorities.iter().any(|entry| {
+                &entry.key == cosigner.key && entry.roles & roles != 0 && entry.paths.is_empty()
+            });
+            if cosigner.is_signer && unrestricted && !approvals.contains(&cosigner.key) {
+                approvals.push(cosigner.key);
+            }
+        }
+        if approvals.len() < self.threshold as usize {
+            return Err(OnchainMetadataError::NotEnoughSigners.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the JSON data is still at `expected` when the writer asked for it, failing with
+    /// `RevisionMismatch` if another write landed first.
+    pub fn assert_revision(&self, expected: Option<u64>) -> ProgramResult {
+        match expected {
+            Some(expected) if expected != self.revision => {
+                Err(OnchainMetadataError::RevisionMismatch.into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Records a change to the JSON account or its metadata by bumping the revision and stamping
+    /// the current slot.
+    pub fn record_change(&mut self) -> ProgramResult {
+        self.revision += 1;
+        self.last_updated_slot = Clock::get()?.slot;
+
+        Ok(())
+    }
+
+    /// Records a write of `value` to the JSON account like `record_change`, also committing to
+    /// the canonical form of the new contents.
+    pub fn record_write(&mut self, value: &Value) -> ProgramResult {
+        self.record_change()?;
+        self.hash = canonical::hash(value);
+
+        Ok(())
+    }
+
+    /// Checks that every change from `old_data` to `new` falls under the paths granted to `key`,
+    /// failing with `PathNotPermitted` otherwise.
+    ///
+    /// `old_data` is the stored data, which is only decoded for authorities with restricted paths.
+    pub fn assert_paths(&self, key: &Pubkey, old_data: &[u8], new: &Value) -> ProgramResult {
+        let prefixes = match self.authorities.iter().find(|entry| &entry.key == key) {
+            Some(entry) if !entry.paths.is_empty() => entry
+                .paths
+                .iter()
+                .map(|prefix| pointer::parse(prefix))
+                .collect::<Result<Vec<_>, _>>()?,
+            _ => return Ok(()),
+        };
+
+        let old = self.encoding.deserialize(old_data).unwrap_or(Value::Null);
+        let permitted = pointer::changed_paths(&old, new)
+            .iter()
+            .all(|path| prefixes.iter().any(|prefix| path.starts_with(prefix)));
+        if !permitted {
+            return Err(OnchainMetadataError::PathNotPermitted.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// An attester's ed25519 signature vouching for the JSON data at a given revision.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq, ShankAccount)]
+pub struct Attestation {
+    pub bump: u8,
+    pub json_account: Pubkey,
+    pub attester: Pubkey,
+    /// The revision of the JSON data that was attested.
+    pub revision: u64,
+    /// The hash of the JSON data that was attested.
+    pub hash: [u8; 32],
+    /// The slot the attestation was recorded in.
+    pub slot: u64,
+}
+
+impl Attestation {
+    /// Returns the message an attester signs to vouch that `json_account` held data with `hash`
+    /// at `revision`.
+    pub fn message(json_account: &Pubkey, revision: u64, hash: &[u8; 32]) -> Vec<u8> {
+        [json_account.as_ref(), &revision.to_le_bytes(), hash].concat()
+    }
+}
+
+/// The layout of `JsonMetadata` before authorities carried roles.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug)]
+pub struct LegacyJsonMetadata {
     pub bump: u8,
     pub mutable: bool,
     pub authorities: Vec<Pubkey>,
 }
+
+impl From<LegacyJsonMetadata> for JsonMetadata {
+    /// Legacy authorities had full control over the account, so they keep every role.
+    fn from(legacy: LegacyJsonMetadata) -> Self {
+        Self {
+            bump: legacy.bump,
+            version: METADATA_VERSION,
+            mutable: legacy.mutable,
+            authorities: legacy
+                .authorities
+                .into_iter()
+                .map(|key| AuthorityEntry {
+                    key,
+                    roles: ROLE_ALL,
+                    paths: Vec::new(),
+                })
+                .collect(),
+            threshold: 1,
+            schema: None,
+            pending_authorities: Vec::new(),
+            revision: 0,
+            last_updated_slot: 0,
+            hash: [0; 32],
+            encoding: Encoding::Json,
+        }
+    }
+}
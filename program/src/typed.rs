@@ -0,0 +1,82 @@
+//! Helpers for treating a JSON account as a strongly typed value.
+
+use serde::{de::DeserializeOwned, Serialize};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use thiserror::Error;
+
+use crate::{
+    instruction::{self, AppendValueArgs, SetValueArgs},
+    state::Encoding,
+};
+
+#[derive(Error, Debug)]
+pub enum TypedError {
+    /// The account data could not be decoded with the account's encoding.
+    #[error("the account data is not valid {0:?}")]
+    NotDecodable(Encoding),
+
+    /// The account data is a JSON document, but its shape doesn't match the requested type.
+    #[error("the JSON document does not match the requested type: {0}")]
+    Mismatch(serde_json::Error),
+
+    /// The value could not be serialized as JSON.
+    #[error("the value could not be serialized as JSON: {0}")]
+    Serialize(serde_json::Error),
+}
+
+/// Deserializes the data of a JSON account into `T`, decoding it with `encoding`, which is the
+/// `encoding` recorded in the account's `JsonMetadata`.
+pub fn read_typed<T: DeserializeOwned>(data: &[u8], encoding: Encoding) -> Result<T, TypedError> {
+    let value = encoding
+        .deserialize(data)
+        .map_err(|_| TypedError::NotDecodable(encoding))?;
+    serde_json::from_value(value).map_err(TypedError::Mismatch)
+}
+
+/// Builds a `SetValue` instruction that merges the JSON form of `value` into the document.
+pub fn set_typed<T: Serialize>(
+    program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    rent_destination: Pubkey,
+    value: &T,
+) -> Result<Instruction, TypedError> {
+    Ok(instruction::set_value(
+        program_id,
+        json_account,
+        json_metadata_account,
+        authority,
+        payer,
+        rent_destination,
+        SetValueArgs {
+            value: serde_json::to_string(value).map_err(TypedError::Serialize)?,
+            expected_revision: None,
+        },
+    ))
+}
+
+/// Builds an `AppendValue` instruction that appends the JSON form of `value` to the document.
+pub fn append_typed<T: Serialize>(
+    program_id: Pubkey,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    rent_destination: Pubkey,
+    value: &T,
+) -> Result<Instruction, TypedError> {
+    Ok(instruction::append_value(
+        program_id,
+        json_account,
+        json_metadata_account,
+        authority,
+        payer,
+        rent_destination,
+        AppendValueArgs {
+            value: serde_json::to_string(value).map_err(TypedError::Serialize)?,
+            expected_revision: None,
+        },
+    ))
+}
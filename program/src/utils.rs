@@ -0,0 +1,28 @@
+use mpl_utils::resize_or_reallocate_account_raw;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, rent::Rent, sysvar::Sysvar,
+};
+
+/// Resizes a program-owned account to `new_size`, funding any growth from `payer` and moving
+/// lamports above the rent-exempt minimum, such as those freed by shrinking, to
+/// `rent_destination`.
+///
+/// The refund moves lamports directly, so no CPI may follow this call in the same instruction.
+pub fn resize_and_refund<'a>(
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    rent_destination: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    new_size: usize,
+) -> ProgramResult {
+    resize_or_reallocate_account_raw(account, payer, system_program, new_size)?;
+
+    let minimum_balance = Rent::get()?.minimum_balance(new_size);
+    let excess = account.lamports().saturating_sub(minimum_balance);
+    if excess > 0 {
+        **account.try_borrow_mut_lamports()? -= excess;
+        **rent_destination.try_borrow_mut_lamports()? += excess;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+mod utils;
+
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{self, SetValueArgs},
+    pda::{find_attestation_account, find_metadata_account},
+    state::{Attestation, JsonMetadata},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    ed25519_instruction::new_ed25519_instruction,
+    signature::{Keypair, Signer},
+};
+use utils::*;
+
+async fn metadata(context: &mut ProgramTestContext, address: Pubkey) -> JsonMetadata {
+    context
+        .banks_client
+        .get_account_data_with_borsh(address)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_attest() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+    let attester = Keypair::new();
+    let signer = ed25519_dalek::Keypair::from_bytes(&attester.to_bytes()).unwrap();
+
+    let set_value = |value: &str| {
+        instruction::set_value(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+            payer,
+            SetValueArgs {
+                value: value.to_string(),
+                expected_revision: None,
+            },
+        )
+    };
+    let attest = |attester: Pubkey| {
+        instruction::attest(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            attester,
+            payer,
+        )
+    };
+
+    process(&mut context, &[set_value(r#"{"name": "Token"}"#)], &[])
+        .await
+        .unwrap();
+    let json_metadata = metadata(&mut context, json_metadata_account).await;
+    let message = Attestation::message(&json_account, json_metadata.revision, &json_metadata.hash);
+    let signature = new_ed25519_instruction(&signer, &message);
+
+    // The signature must be verified by the instruction right before.
+    let result = process(&mut context, &[attest(attester.pubkey())], &[]).await;
+    assert_error(result, OnchainMetadataError::InvalidSignatureInstruction);
+
+    // The attestation is recorded at the address derived from the signing key.
+    let result = process(
+        &mut context,
+        &[signature.clone(), attest(Keypair::new().pubkey())],
+        &[],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::AttestationDerivedKeyInvalid);
+
+    process(
+        &mut context,
+        &[signature.clone(), attest(attester.pubkey())],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let (attestation_account, bump) = find_attestation_account(&json_account, &attester.pubkey());
+    let attestation: Attestation = context
+        .banks_client
+        .get_account_data_with_borsh(attestation_account)
+        .await
+        .unwrap();
+    assert_eq!(attestation.bump, bump);
+    assert_eq!(attestation.json_account, json_account);
+    assert_eq!(attestation.attester, attester.pubkey());
+    assert_eq!(attestation.revision, 1);
+    assert_eq!(attestation.hash, json_metadata.hash);
+
+    // A signature over an older revision no longer matches once the data changes.
+    process(&mut context, &[set_value(r#"{"name": "Other"}"#)], &[])
+        .await
+        .unwrap();
+    let result = process(&mut context, &[signature, attest(attester.pubkey())], &[]).await;
+    assert_error(result, OnchainMetadataError::AttestationMismatch);
+
+    // Attesting the new revision overwrites the previous attestation.
+    let json_metadata = metadata(&mut context, json_metadata_account).await;
+    let message = Attestation::message(&json_account, json_metadata.revision, &json_metadata.hash);
+    process(
+        &mut context,
+        &[
+            new_ed25519_instruction(&signer, &message),
+            attest(attester.pubkey()),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    let attestation: Attestation = context
+        .banks_client
+        .get_account_data_with_borsh(attestation_account)
+        .await
+        .unwrap();
+    assert_eq!(attestation.revision, 2);
+    assert_eq!(attestation.hash, json_metadata.hash);
+}
+
+#[tokio::test]
+async fn test_attest_rejects_forged_signature() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let attester = Keypair::new();
+    let signer = ed25519_dalek::Keypair::from_bytes(&attester.to_bytes()).unwrap();
+
+    let json_metadata = metadata(&mut context, json_metadata_account).await;
+    let message = Attestation::message(&json_account, json_metadata.revision, &json_metadata.hash);
+    let mut signature = new_ed25519_instruction(&signer, &message);
+    // Corrupt the signature, which starts right after the offsets and public key.
+    signature.data[16 + 32] ^= 1;
+
+    let attest = instruction::attest(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        attester.pubkey(),
+        context.payer.pubkey(),
+    );
+    let result = process(&mut context, &[signature, attest], &[]).await;
+    assert!(result.is_err());
+}
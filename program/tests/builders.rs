@@ -0,0 +1,479 @@
+mod utils;
+
+use serde_json::json;
+use solana_json::{
+    instruction::{
+        self, AddAuthorityArgs, AppendValueArgs, BeginUploadArgs, CancelProposalArgs,
+        CommitUploadArgs, DeleteAtPointerArgs, InitializeWithSeedArgs, PatchValueArgs,
+        ProposeAuthorityArgs, RemoveAuthorityArgs, SetAtPointerArgs, SetEncodingArgs,
+        SetSchemaArgs, SetThresholdArgs, SetValueArgs, VerifyHashArgs, WriteChunkArgs,
+    },
+    pda::{
+        find_attestation_account, find_buffer_account, find_json_account, find_metadata_account,
+        find_schema_account,
+    },
+    state::{Attestation, Encoding, JsonMetadata, ROLE_ALL, ROLE_APPENDER, ROLE_WRITER},
+};
+use solana_program::{hash::hash, pubkey::Pubkey};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    ed25519_instruction::new_ed25519_instruction,
+    signature::{Keypair, Signer},
+};
+use utils::*;
+
+async fn metadata(context: &mut ProgramTestContext, address: Pubkey) -> JsonMetadata {
+    context
+        .banks_client
+        .get_account_data_with_borsh(address)
+        .await
+        .unwrap()
+}
+
+/// Drives every instruction builder through the program with the accounts it documents.
+#[tokio::test]
+async fn test_builders() {
+    let mut context = program_test().start_with_context().await;
+    let payer = context.payer.pubkey();
+    let json_keypair = Keypair::new();
+    let json_account = json_keypair.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let (buffer_account, _) = find_buffer_account(&json_account);
+    let (schema_account, _) = find_schema_account(&json_account);
+    let admin = Keypair::new();
+    let writer = Keypair::new();
+
+    process(
+        &mut context,
+        &[instruction::initialize(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+        )],
+        &[&json_keypair],
+    )
+    .await
+    .unwrap();
+
+    process(
+        &mut context,
+        &[
+            instruction::set_value(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                payer,
+                SetValueArgs {
+                    value: r#"{"name": "Token", "tags": []}"#.to_string(),
+                    expected_revision: Some(0),
+                },
+            ),
+            instruction::append_value(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                payer,
+                AppendValueArgs {
+                    value: r#"{"name": " #1"}"#.to_string(),
+                    expected_revision: Some(1),
+                },
+            ),
+            instruction::patch_value(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                payer,
+                PatchValueArgs {
+                    patch: r#"[{"op": "add", "path": "/tags/-", "value": "rare"}]"#.to_string(),
+                    expected_revision: None,
+                },
+            ),
+            instruction::set_at_pointer(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                payer,
+                SetAtPointerArgs {
+                    pointer: "/level".to_string(),
+                    value: "3".to_string(),
+                    expected_revision: None,
+                },
+            ),
+            instruction::delete_at_pointer(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                payer,
+                DeleteAtPointerArgs {
+                    pointer: "/tags".to_string(),
+                    expected_revision: None,
+                },
+            ),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        json_data(&mut context, &json_keypair).await,
+        json!({"name": "Token #1", "level": 3})
+    );
+
+    process(
+        &mut context,
+        &[
+            instruction::add_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                AddAuthorityArgs {
+                    new_authority: Pubkey::new_unique(),
+                    roles: ROLE_APPENDER,
+                    paths: vec![],
+                },
+            ),
+            instruction::propose_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                ProposeAuthorityArgs {
+                    new_authority: admin.pubkey(),
+                    roles: ROLE_ALL,
+                    paths: vec![],
+                },
+            ),
+            instruction::accept_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                admin.pubkey(),
+                payer,
+            ),
+            instruction::propose_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                ProposeAuthorityArgs {
+                    new_authority: writer.pubkey(),
+                    roles: ROLE_WRITER,
+                    paths: vec![],
+                },
+            ),
+            instruction::cancel_proposal(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                CancelProposalArgs {
+                    authority: writer.pubkey(),
+                },
+            ),
+            instruction::propose_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                admin.pubkey(),
+                payer,
+                ProposeAuthorityArgs {
+                    new_authority: writer.pubkey(),
+                    roles: ROLE_WRITER,
+                    paths: vec![],
+                },
+            ),
+            instruction::accept_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                writer.pubkey(),
+                payer,
+            ),
+            instruction::set_threshold(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                SetThresholdArgs { threshold: 1 },
+            ),
+            instruction::remove_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                admin.pubkey(),
+                payer,
+                RemoveAuthorityArgs {
+                    authority: admin.pubkey(),
+                },
+            ),
+        ],
+        &[&admin, &writer],
+    )
+    .await
+    .unwrap();
+    let json_metadata = metadata(&mut context, json_metadata_account).await;
+    assert!(!json_metadata.has_role(&admin.pubkey(), ROLE_ALL));
+    assert!(json_metadata.has_role(&writer.pubkey(), ROLE_WRITER));
+    assert!(json_metadata.pending_authorities.is_empty());
+
+    let bytes = br#"{"name": "Uploaded"}"#;
+    process(
+        &mut context,
+        &[
+            instruction::begin_upload(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                buffer_account,
+                payer,
+                payer,
+                BeginUploadArgs {
+                    size: bytes.len() as u32,
+                },
+            ),
+            instruction::write_chunk(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                buffer_account,
+                payer,
+                WriteChunkArgs {
+                    offset: 0,
+                    bytes: bytes.to_vec(),
+                },
+            ),
+            instruction::commit_upload(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                buffer_account,
+                payer,
+                payer,
+                payer,
+                CommitUploadArgs {
+                    merge: false,
+                    expected_revision: None,
+                },
+            ),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        json_data(&mut context, &json_keypair).await,
+        json!({"name": "Uploaded"})
+    );
+
+    process(
+        &mut context,
+        &[
+            instruction::begin_upload(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                buffer_account,
+                payer,
+                payer,
+                BeginUploadArgs { size: 16 },
+            ),
+            instruction::cancel_upload(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                buffer_account,
+                payer,
+                payer,
+            ),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert!(context
+        .banks_client
+        .get_account(buffer_account)
+        .await
+        .unwrap()
+        .is_none());
+
+    process(
+        &mut context,
+        &[
+            instruction::set_schema(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                schema_account,
+                payer,
+                payer,
+                SetSchemaArgs {
+                    schema: r#"{"type": "object"}"#.to_string(),
+                },
+            ),
+            instruction::remove_schema(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                schema_account,
+                payer,
+                payer,
+                payer,
+            ),
+            instruction::set_encoding(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                payer,
+                SetEncodingArgs {
+                    encoding: Encoding::CanonicalJson,
+                },
+            ),
+            instruction::migrate(solana_json::ID, json_account, json_metadata_account, payer),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    let account = context
+        .banks_client
+        .get_account(json_account)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data, br#"{"name":"Uploaded"}"#);
+    assert!(context
+        .banks_client
+        .get_account(schema_account)
+        .await
+        .unwrap()
+        .is_none());
+
+    let json_metadata = metadata(&mut context, json_metadata_account).await;
+    assert_eq!(json_metadata.encoding, Encoding::CanonicalJson);
+    assert_eq!(json_metadata.hash, hash(&account.data).to_bytes());
+    let attester = Keypair::new();
+    let signer = ed25519_dalek::Keypair::from_bytes(&attester.to_bytes()).unwrap();
+    let message = Attestation::message(&json_account, json_metadata.revision, &json_metadata.hash);
+    process(
+        &mut context,
+        &[
+            instruction::verify_hash(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                VerifyHashArgs {
+                    expected: json_metadata.hash,
+                },
+            ),
+            new_ed25519_instruction(&signer, &message),
+            instruction::attest(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                attester.pubkey(),
+                payer,
+            ),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    let (attestation_account, _) = find_attestation_account(&json_account, &attester.pubkey());
+    let attestation: Attestation = context
+        .banks_client
+        .get_account_data_with_borsh(attestation_account)
+        .await
+        .unwrap();
+    assert_eq!(attestation.revision, json_metadata.revision);
+
+    process(
+        &mut context,
+        &[instruction::close(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert!(context
+        .banks_client
+        .get_account(json_account)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_lifecycle_builders() {
+    let mut context = program_test().start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let (frozen_account, _) = find_json_account(&payer, "frozen", None);
+    let (frozen_metadata_account, _) = find_metadata_account(&frozen_account);
+    let renounced_account = initialize(&mut context).await.pubkey();
+    let (renounced_metadata_account, _) = find_metadata_account(&renounced_account);
+
+    process(
+        &mut context,
+        &[
+            instruction::initialize_with_seed(
+                solana_json::ID,
+                frozen_account,
+                frozen_metadata_account,
+                payer,
+                payer,
+                InitializeWithSeedArgs {
+                    seed: "frozen".to_string(),
+                    mint: None,
+                },
+            ),
+            instruction::freeze(
+                solana_json::ID,
+                frozen_account,
+                frozen_metadata_account,
+                payer,
+            ),
+            instruction::renounce_all(
+                solana_json::ID,
+                renounced_account,
+                renounced_metadata_account,
+                payer,
+                payer,
+            ),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let frozen = metadata(&mut context, frozen_metadata_account).await;
+    assert!(!frozen.mutable);
+    assert!(frozen.has_role(&payer, ROLE_ALL));
+    let renounced = metadata(&mut context, renounced_metadata_account).await;
+    assert!(!renounced.mutable);
+    assert!(renounced.authorities.is_empty());
+}
@@ -0,0 +1,202 @@
+mod utils;
+
+use serde_json::json;
+use solana_json::{
+    canonical,
+    error::OnchainMetadataError,
+    instruction::{self, SetEncodingArgs, SetValueArgs},
+    pda::find_metadata_account,
+    state::{Encoding, JsonMetadata},
+};
+use solana_program::hash::hash;
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+use utils::*;
+
+fn canonical(input: &str) -> String {
+    String::from_utf8(canonical::to_vec(&serde_json::from_str(input).unwrap()).unwrap()).unwrap()
+}
+
+#[test]
+fn test_canonical_rfc_8785_example() {
+    let input = r#"{
+        "numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+        "string": "\u20ac$\u000F\u000aA'\u0042\u0022\u005c\\\"\/",
+        "literals": [null, true, false]
+    }"#;
+    assert_eq!(
+        canonical(input),
+        r#"{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"#
+    );
+}
+
+#[test]
+fn test_canonical_numbers() {
+    let cases = [
+        ("0", "0"),
+        ("-0.0", "0"),
+        ("12.0", "12"),
+        ("-1.5", "-1.5"),
+        ("9007199254740992", "9007199254740992"),
+        ("1e20", "100000000000000000000"),
+        ("1e21", "1e+21"),
+        ("0.000001", "0.000001"),
+        ("1e-7", "1e-7"),
+        ("123e-20", "1.23e-18"),
+        ("-5e-324", "-5e-324"),
+        ("1.7976931348623157e308", "1.7976931348623157e+308"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(canonical(input), expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_canonical_rejects_inexact_integers() {
+    // 2^53 + 1 would be rounded to 2^53 as a double.
+    for input in [
+        "9007199254740993",
+        "-9007199254740993",
+        "[18446744073709551615]",
+    ] {
+        let value = serde_json::from_str(input).unwrap();
+        assert!(canonical::to_vec(&value).is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn test_canonical_hash_keeps_inexact_integers() {
+    // The hash keeps the exact digits, so integers a double can't tell apart hash differently.
+    let hash_of = |input: &str| canonical::hash(&serde_json::from_str(input).unwrap());
+    assert_eq!(
+        hash_of("[18446744073709551615]"),
+        hash(b"[18446744073709551615]").to_bytes()
+    );
+    assert_ne!(hash_of("9007199254740993"), hash_of("9007199254740992"));
+    assert_eq!(hash_of("12.0"), hash(b"12").to_bytes());
+}
+
+#[test]
+fn test_canonical_key_order() {
+    // Keys are ordered by UTF-16 code units, which puts astral characters before U+FB33.
+    let input = r#"{"\u20ac": 0, "\r": 1, "\ufb33": 2, "1": 3, "\ud83d\ude00": 4, "\u0080": 5, "\u00f6": 6}"#;
+    assert_eq!(
+        canonical(input),
+        "{\"\\r\":1,\"1\":3,\"\u{80}\":5,\"\u{f6}\":6,\"\u{20ac}\":0,\"\u{1f600}\":4,\"\u{fb33}\":2}"
+    );
+}
+
+#[tokio::test]
+async fn test_set_encoding() {
+    let mut context = program_test().start_with_context().await;
+    let json_keypair = initialize(&mut context).await;
+    let json_account = json_keypair.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+
+    let set_value = instruction::set_value(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetValueArgs {
+            value: r#"{"price": 12.0, "name": "Token"}"#.to_string(),
+            expected_revision: None,
+        },
+    );
+    process(&mut context, &[set_value], &[]).await.unwrap();
+
+    let set_encoding = instruction::set_encoding(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetEncodingArgs {
+            encoding: Encoding::CanonicalJson,
+        },
+    );
+    process(&mut context, &[set_encoding], &[]).await.unwrap();
+
+    // The existing document is re-encoded and the hash covers the canonical bytes.
+    let account = context
+        .banks_client
+        .get_account(json_account)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data, br#"{"name":"Token","price":12}"#);
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert_eq!(json_metadata.encoding, Encoding::CanonicalJson);
+    assert_eq!(json_metadata.hash, hash(&account.data).to_bytes());
+    assert_eq!(
+        json_data(&mut context, &json_keypair).await,
+        json!({"name": "Token", "price": 12})
+    );
+}
+
+#[tokio::test]
+async fn test_set_value_inexact_integers() {
+    let mut context = program_test().start_with_context().await;
+    let json_keypair = initialize(&mut context).await;
+    let json_account = json_keypair.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+    let set_value = || {
+        instruction::set_value(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+            payer,
+            SetValueArgs {
+                value: r#"{"supply": 18446744073709551615}"#.to_string(),
+                expected_revision: None,
+            },
+        )
+    };
+
+    // Plain JSON accounts store any u64 exactly.
+    process(&mut context, &[set_value()], &[]).await.unwrap();
+    assert_eq!(
+        json_data(&mut context, &json_keypair).await,
+        json!({"supply": u64::MAX})
+    );
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert_eq!(
+        json_metadata.hash,
+        hash(br#"{"supply":18446744073709551615}"#).to_bytes()
+    );
+
+    // Canonical JSON can't represent it, so such accounts reject it.
+    let json_keypair = initialize(&mut context).await;
+    let json_account = json_keypair.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let set_encoding = instruction::set_encoding(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetEncodingArgs {
+            encoding: Encoding::CanonicalJson,
+        },
+    );
+    process(&mut context, &[set_encoding], &[]).await.unwrap();
+    let result = process(&mut context, &[set_value()], &[]).await;
+    assert_error(result, OnchainMetadataError::InvalidJson);
+    assert_eq!(json_data(&mut context, &json_keypair).await, json!(null));
+}
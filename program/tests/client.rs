@@ -0,0 +1,146 @@
+#![cfg(feature = "client")]
+
+mod utils;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use solana_json::{
+    client::{ClientError, JsonAccountClient},
+    state::{Encoding, ROLE_ALL},
+    typed::TypedError,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+use utils::program_test;
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+struct Metadata {
+    name: String,
+    attributes: Vec<Attribute>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+struct Attribute {
+    trait_type: String,
+    value: u64,
+}
+
+#[tokio::test]
+async fn test_client_round_trip() {
+    let (banks_client, payer, _) = program_test().start().await;
+    let mut client = JsonAccountClient::new(banks_client, payer);
+
+    let json_account = client.initialize().await.unwrap();
+    assert_eq!(
+        client.fetch_value(&json_account).await.unwrap(),
+        json!(null)
+    );
+
+    client
+        .set_value(
+            &json_account,
+            &json!({"name": "Token", "attributes": [{"trait_type": "Level", "value": 1}]}),
+        )
+        .await
+        .unwrap();
+    client
+        .set_at_pointer(&json_account, "/attributes/0/value", &json!(2))
+        .await
+        .unwrap();
+    client
+        .append_value(&json_account, &json!({"name": " #1"}))
+        .await
+        .unwrap();
+
+    let metadata: Metadata = client.fetch_as(&json_account).await.unwrap();
+    assert_eq!(
+        metadata,
+        Metadata {
+            name: "Token #1".to_string(),
+            attributes: vec![Attribute {
+                trait_type: "Level".to_string(),
+                value: 2,
+            }],
+        }
+    );
+
+    let account = client.fetch(&json_account).await.unwrap();
+    assert!(account.metadata.has_role(&client.payer(), ROLE_ALL));
+    assert!(account.metadata.mutable);
+
+    client
+        .set_typed(
+            &json_account,
+            &Metadata {
+                name: "Renamed".to_string(),
+                attributes: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    assert!(matches!(
+        client.fetch_as::<Attribute>(&json_account).await,
+        Err(ClientError::Typed(TypedError::Mismatch(_)))
+    ));
+
+    client.close(&json_account).await.unwrap();
+    assert!(matches!(
+        client.fetch(&json_account).await,
+        Err(ClientError::AccountNotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_client_upload() {
+    let (banks_client, payer, _) = program_test().start().await;
+    let owner = payer.pubkey();
+    let mut client = JsonAccountClient::new(banks_client, payer);
+
+    let json_account = client
+        .initialize_with_seed(&owner, "collection", None)
+        .await
+        .unwrap();
+    let attributes: Vec<_> = (0..100)
+        .map(|i| json!({"trait_type": format!("Trait {}", i), "value": i}))
+        .collect();
+    let document = json!({"name": "Large", "attributes": attributes});
+
+    client
+        .upload(&json_account, &document, false)
+        .await
+        .unwrap();
+    assert_eq!(client.fetch_value(&json_account).await.unwrap(), document);
+}
+
+#[tokio::test]
+async fn test_client_message_pack() {
+    let (banks_client, payer, _) = program_test().start().await;
+    let mut client = JsonAccountClient::new(banks_client, payer);
+
+    let json_account = client.initialize().await.unwrap();
+    client
+        .set_encoding(&json_account, Encoding::MessagePack)
+        .await
+        .unwrap();
+    let metadata = Metadata {
+        name: "Token".to_string(),
+        attributes: vec![Attribute {
+            trait_type: "Level".to_string(),
+            value: 1,
+        }],
+    };
+    client.set_typed(&json_account, &metadata).await.unwrap();
+
+    // The stored bytes aren't JSON, but the client decodes them transparently.
+    let account = client.fetch(&json_account).await.unwrap();
+    assert!(serde_json::from_slice::<serde_json::Value>(&account.data).is_err());
+    assert_eq!(account.deserialize::<Metadata>().unwrap(), metadata);
+    assert_eq!(
+        client.fetch_value(&json_account).await.unwrap(),
+        json!({"name": "Token", "attributes": [{"trait_type": "Level", "value": 1}]})
+    );
+    assert!(matches!(
+        client.fetch_as::<Attribute>(&json_account).await,
+        Err(ClientError::Typed(TypedError::Mismatch(_)))
+    ));
+}
@@ -0,0 +1,164 @@
+mod utils;
+
+use serde_json::json;
+use solana_json::{
+    instruction::{self, PatchValueArgs, SetEncodingArgs, SetValueArgs},
+    pda::find_metadata_account,
+    state::{Encoding, JsonMetadata},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::signature::Signer;
+use utils::*;
+
+/// Metadata in the shape of a typical NFT's off-chain JSON.
+fn nft_metadata() -> serde_json::Value {
+    json!({
+        "name": "Creature #4821",
+        "symbol": "CRTR",
+        "description": "One of 10,000 procedurally generated creatures.",
+        "seller_fee_basis_points": 500,
+        "image": "https://arweave.net/6ZsYpHtQkE3M5x8cVmH2oNf1rLJd9Wq7bAaT0uGzKp4",
+        "attributes": [
+            {"trait_type": "Background", "value": "Teal"},
+            {"trait_type": "Body", "value": "Scaled"},
+            {"trait_type": "Eyes", "value": "Laser"},
+            {"trait_type": "Level", "value": 12},
+            {"trait_type": "Speed", "value": 87.5}
+        ],
+        "properties": {
+            "category": "image",
+            "files": [{"uri": "https://arweave.net/6ZsYpHtQkE3M5x8cVmH2oNf1rLJd9Wq7bAaT0uGzKp4", "type": "image/png"}],
+            "creators": [{"address": "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU", "share": 100}]
+        }
+    })
+}
+
+/// Initializes a JSON account with `encoding` and writes `value` to it.
+async fn store(
+    context: &mut ProgramTestContext,
+    encoding: Encoding,
+    value: &serde_json::Value,
+) -> (Pubkey, Pubkey) {
+    let json_account = initialize(context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+
+    let set_encoding = instruction::set_encoding(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetEncodingArgs { encoding },
+    );
+    let set_value = instruction::set_value(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetValueArgs {
+            value: value.to_string(),
+            expected_revision: None,
+        },
+    );
+    process(context, &[set_encoding, set_value], &[])
+        .await
+        .unwrap();
+
+    (json_account, json_metadata_account)
+}
+
+async fn stored_value(
+    context: &mut ProgramTestContext,
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+) -> (usize, serde_json::Value) {
+    let data = context
+        .banks_client
+        .get_account(json_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    let value = json_metadata.encoding.deserialize(&data).unwrap();
+    (data.len(), value)
+}
+
+#[tokio::test]
+async fn test_message_pack_writes() {
+    let mut context = program_test().start_with_context().await;
+    let (json_account, json_metadata_account) =
+        store(&mut context, Encoding::MessagePack, &nft_metadata()).await;
+    let payer = context.payer.pubkey();
+
+    // Writes decode, change and re-encode the stored MessagePack.
+    let patch = instruction::patch_value(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        PatchValueArgs {
+            patch: json!([{"op": "replace", "path": "/attributes/3/value", "value": 13}])
+                .to_string(),
+            expected_revision: None,
+        },
+    );
+    process(&mut context, &[patch], &[]).await.unwrap();
+
+    let mut expected = nft_metadata();
+    expected["attributes"][3]["value"] = json!(13);
+    let (_, value) = stored_value(&mut context, json_account, json_metadata_account).await;
+    assert_eq!(value, expected);
+
+    // Switching back to JSON re-encodes the document as text.
+    let set_encoding = instruction::set_encoding(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetEncodingArgs {
+            encoding: Encoding::Json,
+        },
+    );
+    process(&mut context, &[set_encoding], &[]).await.unwrap();
+    let (_, value) = stored_value(&mut context, json_account, json_metadata_account).await;
+    assert_eq!(value, expected);
+}
+
+#[tokio::test]
+async fn test_message_pack_rent() {
+    let mut context = program_test().start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let document = nft_metadata();
+
+    let mut sizes = Vec::new();
+    for encoding in [Encoding::Json, Encoding::MessagePack] {
+        let (json_account, json_metadata_account) = store(&mut context, encoding, &document).await;
+        let (len, value) = stored_value(&mut context, json_account, json_metadata_account).await;
+        assert_eq!(value, document);
+        println!(
+            "{:?}: {} bytes, {} lamports of rent",
+            encoding,
+            len,
+            rent.minimum_balance(len)
+        );
+        sizes.push(len);
+    }
+
+    let saving = 100 * (sizes[0] - sizes[1]) / sizes[0];
+    println!("MessagePack saves {}% of the document's size", saving);
+    assert!(saving >= 10);
+}
@@ -0,0 +1,358 @@
+mod utils;
+
+use borsh::BorshSerialize;
+use serde_json::json;
+use solana_json::{
+    event::{self, JsonEvent, EVENT_TAG},
+    instruction::{
+        self, AddAuthorityArgs, CancelProposalArgs, ProposeAuthorityArgs, SetAtPointerArgs,
+        SetSchemaArgs, SetValueArgs,
+    },
+    pda::{find_metadata_account, find_schema_account},
+    state::{AuthorityEntry, JsonMetadata, LegacyJsonMetadata, ROLE_ADMIN, ROLE_ALL, ROLE_WRITER},
+};
+use solana_program::{hash::hash, instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::{tokio, ProgramTestBanksClientExt, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use utils::*;
+
+/// Processes `instructions` and returns the events they logged.
+async fn process_events(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Vec<JsonEvent> {
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+    context.last_blockhash = blockhash;
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .unwrap();
+    result.result.unwrap();
+    result
+        .metadata
+        .unwrap()
+        .log_messages
+        .iter()
+        .filter_map(|line| JsonEvent::from_log(line))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_events() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = Keypair::new();
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let payer = context.payer.pubkey();
+
+    let initialize = instruction::initialize(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+    );
+    let events = process_events(&mut context, &[initialize], &[&json_account]).await;
+    assert_eq!(
+        events,
+        vec![JsonEvent::Initialize {
+            json_account: json_account.pubkey(),
+            authority: payer,
+        }]
+    );
+
+    let set_value = instruction::set_value(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetValueArgs {
+            value: r#"{"name": "Token", "attributes": {"a/b": 1, "level": 2}}"#.to_string(),
+            expected_revision: None,
+        },
+    );
+    let set_at_pointer = instruction::set_at_pointer(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetAtPointerArgs {
+            pointer: "/attributes/level".to_string(),
+            value: "3".to_string(),
+            expected_revision: None,
+        },
+    );
+    let events = process_events(&mut context, &[set_value, set_at_pointer], &[]).await;
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 2);
+    match &events[0] {
+        JsonEvent::ValueChanged {
+            json_account: account,
+            revision,
+            changed_paths,
+            ..
+        } => {
+            assert_eq!(account, &json_account.pubkey());
+            assert_eq!(*revision, 1);
+            assert_eq!(
+                changed_paths,
+                &["/attributes/a~1b", "/attributes/level", "/name"]
+            );
+        }
+        other => panic!("unexpected event: {:?}", other),
+    }
+    assert_eq!(
+        events[1],
+        JsonEvent::ValueChanged {
+            json_account: json_account.pubkey(),
+            revision: 2,
+            hash: json_metadata.hash,
+            changed_paths: vec!["/attributes/level".to_string()],
+        }
+    );
+
+    let writer = Pubkey::new_unique();
+    let add_authority = instruction::add_authority(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+        AddAuthorityArgs {
+            new_authority: writer,
+            roles: ROLE_WRITER,
+            paths: vec!["/attributes".to_string()],
+        },
+    );
+    let events = process_events(&mut context, &[add_authority], &[]).await;
+    assert_eq!(
+        events,
+        vec![JsonEvent::AuthoritiesChanged {
+            json_account: json_account.pubkey(),
+            authorities: vec![
+                AuthorityEntry {
+                    key: payer,
+                    roles: ROLE_ALL,
+                    paths: vec![],
+                },
+                AuthorityEntry {
+                    key: writer,
+                    roles: ROLE_WRITER,
+                    paths: vec!["/attributes".to_string()],
+                },
+            ],
+            threshold: 1,
+        }]
+    );
+
+    let close = instruction::close(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+    );
+    let events = process_events(&mut context, &[close], &[]).await;
+    assert_eq!(
+        events,
+        vec![JsonEvent::Close {
+            json_account: json_account.pubkey(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_metadata_events() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let (schema_account, _) = find_schema_account(&json_account);
+    let payer = context.payer.pubkey();
+    let admin = Pubkey::new_unique();
+
+    let propose_authority = instruction::propose_authority(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        ProposeAuthorityArgs {
+            new_authority: admin,
+            roles: ROLE_ADMIN,
+            paths: vec![],
+        },
+    );
+    let cancel_proposal = instruction::cancel_proposal(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        CancelProposalArgs { authority: admin },
+    );
+    let events = process_events(&mut context, &[propose_authority, cancel_proposal], &[]).await;
+    assert_eq!(
+        events,
+        vec![
+            JsonEvent::ProposalsChanged {
+                json_account,
+                pending_authorities: vec![AuthorityEntry {
+                    key: admin,
+                    roles: ROLE_ADMIN,
+                    paths: vec![],
+                }],
+            },
+            JsonEvent::ProposalsChanged {
+                json_account,
+                pending_authorities: vec![],
+            },
+        ]
+    );
+
+    let set_schema = instruction::set_schema(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        schema_account,
+        payer,
+        payer,
+        SetSchemaArgs {
+            schema: r#"{"type": "object"}"#.to_string(),
+        },
+    );
+    let remove_schema = instruction::remove_schema(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        schema_account,
+        payer,
+        payer,
+        payer,
+    );
+    let freeze = instruction::freeze(solana_json::ID, json_account, json_metadata_account, payer);
+    let events = process_events(&mut context, &[set_schema, remove_schema, freeze], &[]).await;
+    assert_eq!(
+        events,
+        vec![
+            JsonEvent::SchemaChanged {
+                json_account,
+                schema: Some(schema_account),
+            },
+            JsonEvent::SchemaChanged {
+                json_account,
+                schema: None,
+            },
+            JsonEvent::Freeze { json_account },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_event() {
+    let json_account = Pubkey::new_unique();
+    let (json_metadata_account, bump) = find_metadata_account(&json_account);
+    let legacy_metadata = LegacyJsonMetadata {
+        bump,
+        mutable: true,
+        authorities: vec![Pubkey::new_unique()],
+    };
+    let mut program_test = program_test();
+    program_test.add_account(
+        json_account,
+        Account {
+            lamports: 1_000_000_000,
+            data: b"null".to_vec(),
+            owner: solana_json::ID,
+            ..Account::default()
+        },
+    );
+    program_test.add_account(
+        json_metadata_account,
+        Account {
+            lamports: 1_000_000_000,
+            data: legacy_metadata.try_to_vec().unwrap(),
+            owner: solana_json::ID,
+            ..Account::default()
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+
+    let migrate = instruction::migrate(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        context.payer.pubkey(),
+    );
+    let events = process_events(&mut context, &[migrate], &[]).await;
+    assert_eq!(
+        events,
+        vec![JsonEvent::Migrate {
+            json_account,
+            revision: 1,
+            hash: hash(b"null").to_bytes(),
+        }]
+    );
+}
+
+#[test]
+fn test_event_decoding() {
+    let event = JsonEvent::Close {
+        json_account: Pubkey::new_unique(),
+    };
+    let data = event.try_to_vec().unwrap();
+
+    assert_eq!(JsonEvent::decode(&[EVENT_TAG, &data]), Some(event.clone()));
+    assert_eq!(JsonEvent::decode(&[b"other", &data]), None);
+    assert_eq!(JsonEvent::decode(&[&data]), None);
+
+    let line = format!(
+        "Program data: {} {}",
+        base64::encode(EVENT_TAG),
+        base64::encode(&data)
+    );
+    assert_eq!(JsonEvent::from_log(&line), Some(event));
+    assert_eq!(JsonEvent::from_log("Program log: Instruction: Close"), None);
+    assert_eq!(JsonEvent::from_log("Program data: not-base64!"), None);
+}
+
+#[test]
+fn test_event_paths() {
+    assert_eq!(
+        event::merge_paths(&json!({"a": {"b": 1, "c": {}}, "~": null})),
+        ["/a/b", "/a/c", "/~0"]
+    );
+    assert_eq!(event::merge_paths(&json!([1, 2])), [""]);
+    assert_eq!(
+        event::patch_paths(&json!([
+            {"op": "test", "path": "/a", "value": 1},
+            {"op": "move", "from": "/a", "path": "/b"},
+            {"op": "remove", "path": "/c"}
+        ])),
+        ["/a", "/b", "/c"]
+    );
+}
@@ -0,0 +1,68 @@
+mod utils;
+
+use solana_json::{error::OnchainMetadataError, instruction, pda::find_metadata_account};
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+use utils::*;
+
+#[tokio::test]
+async fn test_freeze_rejects_writes() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let payer = context.payer.pubkey();
+
+    let set_value = |value: &str| {
+        instruction::set_value(
+            solana_json::ID,
+            json_account.pubkey(),
+            json_metadata_account,
+            payer,
+            payer,
+            payer,
+            instruction::SetValueArgs {
+                value: value.to_string(),
+                expected_revision: None,
+            },
+        )
+    };
+
+    process(&mut context, &[set_value(r#"{"name": "Token"}"#)], &[])
+        .await
+        .unwrap();
+
+    let freeze = instruction::freeze(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+    );
+    process(&mut context, &[freeze.clone()], &[]).await.unwrap();
+
+    let result = process(&mut context, &[set_value(r#"{"name": "Other"}"#)], &[]).await;
+    assert_error(result, OnchainMetadataError::Immutable);
+
+    let result = process(&mut context, &[freeze], &[]).await;
+    assert_error(result, OnchainMetadataError::Immutable);
+
+    assert_eq!(
+        json_data(&mut context, &json_account).await,
+        serde_json::json!({"name": "Token"})
+    );
+
+    // Frozen accounts can still be closed, so their rent isn't stranded.
+    let close = instruction::close(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+    );
+    process(&mut context, &[close], &[]).await.unwrap();
+    assert!(context
+        .banks_client
+        .get_account(json_account.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,125 @@
+mod utils;
+
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{self, SetEncodingArgs, SetValueArgs, VerifyHashArgs},
+    pda::find_metadata_account,
+    state::{Encoding, JsonMetadata},
+};
+use solana_program::hash::hash;
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+use utils::*;
+
+#[tokio::test]
+async fn test_verify_hash() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+
+    let verify_hash = |expected: [u8; 32]| {
+        instruction::verify_hash(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            VerifyHashArgs { expected },
+        )
+    };
+
+    // A new account commits to its initial null document.
+    process(&mut context, &[verify_hash(hash(b"null").to_bytes())], &[])
+        .await
+        .unwrap();
+
+    let set_value = instruction::set_value(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetValueArgs {
+            value: r#"{"name": "Token", "level": 1}"#.to_string(),
+            expected_revision: None,
+        },
+    );
+    process(&mut context, &[set_value], &[]).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(json_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    // The hash covers the canonical form, not the bytes as they were sent.
+    assert_ne!(json_metadata.hash, hash(&account.data).to_bytes());
+    assert_eq!(
+        json_metadata.hash,
+        hash(br#"{"level":1,"name":"Token"}"#).to_bytes()
+    );
+
+    process(&mut context, &[verify_hash(json_metadata.hash)], &[])
+        .await
+        .unwrap();
+    let result = process(&mut context, &[verify_hash(hash(b"null").to_bytes())], &[]).await;
+    assert_error(result, OnchainMetadataError::HashMismatch);
+}
+
+#[tokio::test]
+async fn test_hash_ignores_encoding() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+
+    let set_value = instruction::set_value(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetValueArgs {
+            value: r#"{"name": "Token", "level": 1}"#.to_string(),
+            expected_revision: None,
+        },
+    );
+    let set_encoding = instruction::set_encoding(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetEncodingArgs {
+            encoding: Encoding::MessagePack,
+        },
+    );
+    process(&mut context, &[set_value, set_encoding], &[])
+        .await
+        .unwrap();
+
+    // Re-encoding the document changes the stored bytes but not what the hash commits to.
+    let expected = hash(br#"{"level":1,"name":"Token"}"#).to_bytes();
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert_eq!(json_metadata.encoding, Encoding::MessagePack);
+    assert_eq!(json_metadata.hash, expected);
+
+    let verify_hash = instruction::verify_hash(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        VerifyHashArgs { expected },
+    );
+    process(&mut context, &[verify_hash], &[]).await.unwrap();
+}
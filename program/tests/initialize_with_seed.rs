@@ -0,0 +1,193 @@
+mod utils;
+
+use serde_json::json;
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{self, InitializeWithSeedArgs, SetValueArgs},
+    pda::{find_json_account, find_metadata_account},
+    state::{AuthorityEntry, JsonMetadata, ROLE_ALL},
+};
+use solana_program::{pubkey, pubkey::Pubkey};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    signature::{Keypair, Signer},
+};
+use utils::*;
+
+const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Adds an account owned by `owner` at a new address, standing in for a mint.
+fn add_mint(program_test: &mut ProgramTest, owner: Pubkey) -> Pubkey {
+    let mint = Pubkey::new_unique();
+    program_test.add_account(
+        mint,
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![0; 82],
+            owner,
+            ..Account::default()
+        },
+    );
+    mint
+}
+
+#[tokio::test]
+async fn test_initialize_with_seed() {
+    let mut program_test = program_test();
+    let mint = add_mint(&mut program_test, TOKEN_PROGRAM_ID);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let (json_account, _) = find_json_account(&payer, "metadata", Some(&mint));
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let initialize = instruction::initialize_with_seed(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        InitializeWithSeedArgs {
+            seed: "metadata".to_string(),
+            mint: Some(mint),
+        },
+    );
+    let set_value = instruction::set_value(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetValueArgs {
+            value: r#"{"name": "Token"}"#.to_string(),
+            expected_revision: None,
+        },
+    );
+    process(&mut context, &[initialize, set_value], &[])
+        .await
+        .unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(json_account)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.owner, solana_json::ID);
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&account.data).unwrap(),
+        json!({"name": "Token"})
+    );
+
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert_eq!(
+        json_metadata.authorities,
+        vec![AuthorityEntry {
+            key: payer,
+            roles: ROLE_ALL,
+            paths: vec![],
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_initialize_with_seed_for_owner() {
+    let mut context = program_test().start_with_context().await;
+    let owner = Keypair::new();
+
+    // The payer sponsors an account that belongs to `owner`, who doesn't sign.
+    let (json_account, _) = find_json_account(&owner.pubkey(), "profile", None);
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let initialize = instruction::initialize_with_seed(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        owner.pubkey(),
+        context.payer.pubkey(),
+        InitializeWithSeedArgs {
+            seed: "profile".to_string(),
+            mint: None,
+        },
+    );
+    process(&mut context, &[initialize], &[]).await.unwrap();
+
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert_eq!(
+        json_metadata.authorities,
+        vec![AuthorityEntry {
+            key: owner.pubkey(),
+            roles: ROLE_ALL,
+            paths: vec![],
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_initialize_with_seed_rejects_wrong_address() {
+    let mut program_test = program_test();
+    let mint = add_mint(&mut program_test, TOKEN_PROGRAM_ID);
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    // Derived without the mint, so it doesn't match the arguments.
+    let (json_account, _) = find_json_account(&payer, "metadata", None);
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let initialize = instruction::initialize_with_seed(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        InitializeWithSeedArgs {
+            seed: "metadata".to_string(),
+            mint: Some(mint),
+        },
+    );
+    let result = process(&mut context, &[initialize], &[]).await;
+    assert_error(result, OnchainMetadataError::JsonDerivedKeyInvalid);
+}
+
+#[tokio::test]
+async fn test_initialize_with_seed_rejects_invalid_seeds() {
+    let mut program_test = program_test();
+    let fake_mint = add_mint(&mut program_test, Pubkey::new_unique());
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let initialize = |seed: &str, mint: Option<Pubkey>| {
+        let (json_account, _) = find_json_account(&payer, seed, mint.as_ref());
+        let (json_metadata_account, _) = find_metadata_account(&json_account);
+        instruction::initialize_with_seed(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+            InitializeWithSeedArgs {
+                seed: seed.to_string(),
+                mint,
+            },
+        )
+    };
+
+    let result = process(&mut context, &[initialize("", None)], &[]).await;
+    assert_error(result, OnchainMetadataError::InvalidSeed);
+
+    // The mint must be owned by a token program.
+    let result = process(
+        &mut context,
+        &[initialize("metadata", Some(fake_mint))],
+        &[],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::InvalidMint);
+}
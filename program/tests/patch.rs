@@ -0,0 +1,222 @@
+mod utils;
+
+use serde_json::json;
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{self, PatchValueArgs, SetValueArgs},
+    patch,
+    pda::find_metadata_account,
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use utils::*;
+
+fn patch_value(
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    patch: serde_json::Value,
+) -> Instruction {
+    instruction::patch_value(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        authority,
+        payer,
+        payer,
+        PatchValueArgs {
+            patch: patch.to_string(),
+            expected_revision: None,
+        },
+    )
+}
+
+#[test]
+fn test_patch_operations() {
+    let mut doc = json!({
+        "name": "Token",
+        "attributes": [{"trait": "a"}, {"trait": "c"}],
+        "stats": {"hp": 10},
+    });
+
+    let ops = json!([
+        {"op": "test", "path": "/name", "value": "Token"},
+        {"op": "add", "path": "/attributes/1", "value": {"trait": "b"}},
+        {"op": "add", "path": "/attributes/-", "value": {"trait": "d"}},
+        {"op": "remove", "path": "/attributes/0"},
+        {"op": "replace", "path": "/stats/hp", "value": 12.0},
+        {"op": "copy", "from": "/stats", "path": "/base_stats"},
+        {"op": "move", "from": "/name", "path": "/title"},
+        {"op": "test", "path": "/base_stats/hp", "value": 12},
+    ]);
+    patch::apply(&mut doc, &ops).unwrap();
+
+    assert_eq!(
+        doc,
+        json!({
+            "title": "Token",
+            "attributes": [{"trait": "b"}, {"trait": "c"}, {"trait": "d"}],
+            "stats": {"hp": 12.0},
+            "base_stats": {"hp": 12.0},
+        })
+    );
+}
+
+#[test]
+fn test_patch_escaped_pointer() {
+    let mut doc = json!({"a/b": {"m~n": 1}});
+    patch::apply(
+        &mut doc,
+        &json!([{"op": "replace", "path": "/a~1b/m~0n", "value": 2}]),
+    )
+    .unwrap();
+    assert_eq!(doc, json!({"a/b": {"m~n": 2}}));
+}
+
+#[test]
+fn test_patch_failures() {
+    let doc = json!({"list": [1, 2], "name": "Token", "supply": 9007199254740993u64});
+    let failing = [
+        json!([{"op": "test", "path": "/name", "value": "Other"}]),
+        json!([{"op": "test", "path": "/supply", "value": 9007199254740992u64}]),
+        json!([{"op": "remove", "path": "/missing"}]),
+        json!([{"op": "replace", "path": "/list/2", "value": 3}]),
+        json!([{"op": "add", "path": "/list/01", "value": 3}]),
+        json!([{"op": "add", "path": "/missing/child", "value": 3}]),
+        json!([{"op": "move", "from": "/list", "path": "/list/0"}]),
+        json!([{"op": "bogus", "path": "/name"}]),
+        json!({"op": "add", "path": "/name", "value": 1}),
+    ];
+
+    for ops in failing {
+        let mut patched = doc.clone();
+        assert_eq!(
+            patch::apply(&mut patched, &ops),
+            Err(OnchainMetadataError::PatchFailed),
+            "{}",
+            ops
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_patch_value() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let payer = context.payer.pubkey();
+
+    let set_value = instruction::set_value(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetValueArgs {
+            value: r#"{"name": "Token", "attributes": []}"#.to_string(),
+            expected_revision: None,
+        },
+    );
+    process(&mut context, &[set_value], &[]).await.unwrap();
+
+    let ix = patch_value(
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+        json!([
+            {"op": "test", "path": "/name", "value": "Token"},
+            {"op": "add", "path": "/attributes/-", "value": {"trait": "a"}},
+            {"op": "replace", "path": "/name", "value": "Token #1"},
+        ]),
+    );
+    process(&mut context, &[ix], &[]).await.unwrap();
+
+    assert_eq!(
+        json_data(&mut context, &json_account).await,
+        json!({"name": "Token #1", "attributes": [{"trait": "a"}]})
+    );
+}
+
+#[tokio::test]
+async fn test_patch_value_requires_authority() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let outsider = Keypair::new();
+
+    let ix = patch_value(
+        json_account.pubkey(),
+        json_metadata_account,
+        outsider.pubkey(),
+        context.payer.pubkey(),
+        json!([{"op": "add", "path": "/name", "value": "Token"}]),
+    );
+    let result = process(&mut context, &[ix], &[&outsider]).await;
+    assert_error(result, OnchainMetadataError::InvalidAuthority);
+
+    assert_eq!(json_data(&mut context, &json_account).await, json!(null));
+}
+
+#[tokio::test]
+async fn test_patch_value_rejects_wrong_metadata() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let other_account = initialize(&mut context).await;
+    let (other_metadata_account, _) = find_metadata_account(&other_account.pubkey());
+    let payer = context.payer.pubkey();
+
+    let ix = patch_value(
+        json_account.pubkey(),
+        other_metadata_account,
+        payer,
+        payer,
+        json!([{"op": "add", "path": "/name", "value": "Token"}]),
+    );
+    let result = process(&mut context, &[ix], &[]).await;
+    assert_error(result, OnchainMetadataError::MetadataDerivedKeyInvalid);
+}
+
+#[tokio::test]
+async fn test_patch_value_is_atomic() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let payer = context.payer.pubkey();
+
+    let set_value = instruction::set_value(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetValueArgs {
+            value: r#"{"name": "Token"}"#.to_string(),
+            expected_revision: None,
+        },
+    );
+    process(&mut context, &[set_value], &[]).await.unwrap();
+
+    // The failing test runs after a successful add, which must not be written either.
+    let ix = patch_value(
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+        json!([
+            {"op": "add", "path": "/symbol", "value": "TKN"},
+            {"op": "test", "path": "/name", "value": "Other"},
+        ]),
+    );
+    let result = process(&mut context, &[ix], &[]).await;
+    assert_error(result, OnchainMetadataError::PatchFailed);
+
+    assert_eq!(
+        json_data(&mut context, &json_account).await,
+        json!({"name": "Token"})
+    );
+}
@@ -0,0 +1,109 @@
+use serde_json::json;
+use solana_json::{error::OnchainMetadataError, pointer};
+
+#[test]
+fn test_pointer_set() {
+    let mut doc = json!({"attributes": [1, 2], "name": "Token"});
+
+    pointer::set(&mut doc, &pointer::parse("/name").unwrap(), json!(null)).unwrap();
+    pointer::set(
+        &mut doc,
+        &pointer::parse("/attributes/0").unwrap(),
+        json!(5),
+    )
+    .unwrap();
+    pointer::set(
+        &mut doc,
+        &pointer::parse("/attributes/2").unwrap(),
+        json!(6),
+    )
+    .unwrap();
+    pointer::set(
+        &mut doc,
+        &pointer::parse("/attributes/-").unwrap(),
+        json!(7),
+    )
+    .unwrap();
+    pointer::set(&mut doc, &pointer::parse("/a~1b").unwrap(), json!({})).unwrap();
+
+    assert_eq!(
+        doc,
+        json!({"attributes": [5, 2, 6, 7], "name": null, "a/b": {}})
+    );
+
+    assert_eq!(
+        pointer::set(
+            &mut doc,
+            &pointer::parse("/attributes/9").unwrap(),
+            json!(1)
+        ),
+        Err(OnchainMetadataError::PointerNotFound)
+    );
+    assert_eq!(
+        pointer::set(&mut doc, &pointer::parse("/missing/key").unwrap(), json!(1)),
+        Err(OnchainMetadataError::PointerNotFound)
+    );
+}
+
+#[test]
+fn test_pointer_remove() {
+    let mut doc = json!({"attributes": [1, 2, 3], "name": "Token"});
+
+    assert_eq!(
+        pointer::remove(&mut doc, &pointer::parse("/attributes/1").unwrap()),
+        Ok(json!(2))
+    );
+    assert_eq!(
+        pointer::remove(&mut doc, &pointer::parse("/name").unwrap()),
+        Ok(json!("Token"))
+    );
+    assert_eq!(doc, json!({"attributes": [1, 3]}));
+
+    assert_eq!(
+        pointer::remove(&mut doc, &pointer::parse("/name").unwrap()),
+        Err(OnchainMetadataError::PointerNotFound)
+    );
+    assert_eq!(
+        pointer::remove(&mut doc, &pointer::parse("").unwrap()),
+        Err(OnchainMetadataError::InvalidPointer)
+    );
+}
+
+#[test]
+fn test_pointer_parse() {
+    assert_eq!(pointer::parse(""), Ok(vec![]));
+    assert_eq!(
+        pointer::parse("/a~0b/c~1d/"),
+        Ok(vec!["a~b".to_string(), "c/d".to_string(), String::new()])
+    );
+    assert_eq!(
+        pointer::parse("name"),
+        Err(OnchainMetadataError::InvalidPointer)
+    );
+    assert_eq!(
+        pointer::parse("/a~2"),
+        Err(OnchainMetadataError::InvalidPointer)
+    );
+}
+
+#[test]
+fn test_changed_paths() {
+    let old = json!({"name": "Token", "stats": {"hp": 1, "xp": 2}, "tags": ["a", "b"]});
+    let new = json!({"stats": {"hp": 1, "xp": 3, "mp": 4}, "tags": ["a", "c"], "level": 1});
+
+    assert_eq!(
+        pointer::changed_paths(&old, &new),
+        vec![
+            vec!["name".to_string()],
+            vec!["stats".to_string(), "xp".to_string()],
+            vec!["stats".to_string(), "mp".to_string()],
+            vec!["tags".to_string(), "1".to_string()],
+            vec!["level".to_string()],
+        ]
+    );
+    assert_eq!(
+        pointer::changed_paths(&json!([1]), &json!([1, 2])),
+        vec![Vec::<String>::new()]
+    );
+    assert!(pointer::changed_paths(&old, &old).is_empty());
+}
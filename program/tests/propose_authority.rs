@@ -0,0 +1,126 @@
+mod utils;
+
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{self, CancelProposalArgs, ProposeAuthorityArgs},
+    pda::find_metadata_account,
+    state::{AuthorityEntry, JsonMetadata, ROLE_WRITER},
+};
+use solana_program::system_instruction;
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use utils::*;
+
+#[tokio::test]
+async fn test_propose_and_accept_authority() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+    let custodian = Keypair::new();
+    let typo = Keypair::new();
+
+    let propose = |key| {
+        instruction::propose_authority(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+            ProposeAuthorityArgs {
+                new_authority: key,
+                roles: ROLE_WRITER,
+                paths: vec!["/stats".to_string()],
+            },
+        )
+    };
+    let accept = |key| {
+        instruction::accept_authority(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            key,
+            key,
+        )
+    };
+    let cancel = |signer, key| {
+        instruction::cancel_proposal(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            signer,
+            signer,
+            CancelProposalArgs { authority: key },
+        )
+    };
+
+    process(
+        &mut context,
+        &[
+            system_instruction::transfer(&payer, &custodian.pubkey(), 100_000_000),
+            system_instruction::transfer(&payer, &typo.pubkey(), 100_000_000),
+            propose(custodian.pubkey()),
+            propose(typo.pubkey()),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    // Proposals grant nothing until they are accepted.
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert_eq!(json_metadata.pending_authorities.len(), 2);
+    assert!(!json_metadata.has_role(&custodian.pubkey(), ROLE_WRITER));
+
+    // Only an admin or the proposed key can cancel a proposal.
+    let result = process(
+        &mut context,
+        &[cancel(custodian.pubkey(), typo.pubkey())],
+        &[&custodian],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::InvalidAuthority);
+    process(&mut context, &[cancel(payer, typo.pubkey())], &[])
+        .await
+        .unwrap();
+
+    let result = process(&mut context, &[accept(typo.pubkey())], &[&typo]).await;
+    assert_error(result, OnchainMetadataError::ProposalNotFound);
+
+    process(&mut context, &[accept(custodian.pubkey())], &[&custodian])
+        .await
+        .unwrap();
+
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert!(json_metadata.pending_authorities.is_empty());
+    assert_eq!(
+        json_metadata.authorities[1],
+        AuthorityEntry {
+            key: custodian.pubkey(),
+            roles: ROLE_WRITER,
+            paths: vec!["/stats".to_string()],
+        }
+    );
+
+    // A proposed authority can decline the proposal itself.
+    process(&mut context, &[propose(typo.pubkey())], &[])
+        .await
+        .unwrap();
+    process(
+        &mut context,
+        &[cancel(typo.pubkey(), typo.pubkey())],
+        &[&typo],
+    )
+    .await
+    .unwrap();
+    let result = process(&mut context, &[cancel(payer, typo.pubkey())], &[]).await;
+    assert_error(result, OnchainMetadataError::ProposalNotFound);
+}
@@ -0,0 +1,176 @@
+use borsh::BorshSerialize;
+use solana_json::{
+    error::OnchainMetadataError,
+    pda::find_metadata_account,
+    reader,
+    state::{Encoding, JsonMetadata},
+};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+const DOC: &[u8] = br#"{
+    "name": "Token \"One\"",
+    "level": 3,
+    "tags": [],
+    "a/b": {"~": true},
+    "attributes": [
+        {"trait_type": "Hat", "value": "Crown"},
+        {"trait_type": "Eyes", "value": [1, {"x": "]"}]},
+        {"trait_type": "Speed", "value": 1.5},
+        {"trait_type": "Rarity", "value": 42}
+    ]
+}"#;
+
+#[test]
+fn test_reader_get() {
+    assert_eq!(reader::get(DOC, "/level").unwrap(), b"3");
+    assert_eq!(
+        reader::get(DOC, "/name").unwrap(),
+        br#""Token \"One\"""#.as_slice()
+    );
+    assert_eq!(
+        reader::get(DOC, "/attributes/1/value").unwrap(),
+        br#"[1, {"x": "]"}]"#.as_slice()
+    );
+    assert_eq!(
+        reader::get(DOC, "/attributes/1/value/1/x").unwrap(),
+        br#""]""#
+    );
+    assert_eq!(reader::get(DOC, "/a~1b/~0").unwrap(), b"true");
+    assert_eq!(reader::get(DOC, "").unwrap(), DOC);
+
+    assert_eq!(
+        reader::get(DOC, "/missing"),
+        Err(OnchainMetadataError::PointerNotFound)
+    );
+    assert_eq!(
+        reader::get(DOC, "/attributes/4"),
+        Err(OnchainMetadataError::PointerNotFound)
+    );
+    assert_eq!(
+        reader::get(DOC, "/attributes/01"),
+        Err(OnchainMetadataError::PointerNotFound)
+    );
+    assert_eq!(
+        reader::get(DOC, "/tags/0"),
+        Err(OnchainMetadataError::PointerNotFound)
+    );
+    assert_eq!(
+        reader::get(DOC, "/level/0"),
+        Err(OnchainMetadataError::PointerNotFound)
+    );
+    assert_eq!(
+        reader::get(DOC, "level"),
+        Err(OnchainMetadataError::InvalidPointer)
+    );
+    assert_eq!(
+        reader::get(DOC, "/a~2b"),
+        Err(OnchainMetadataError::InvalidPointer)
+    );
+    assert_eq!(
+        reader::get(br#"{"level": 3"#, "/name"),
+        Err(OnchainMetadataError::InvalidJson)
+    );
+}
+
+#[test]
+fn test_reader_get_as() {
+    assert_eq!(reader::get_as::<u64>(DOC, "/attributes/3/value"), Ok(42));
+    assert_eq!(reader::get_as::<f64>(DOC, "/attributes/2/value"), Ok(1.5));
+    assert_eq!(
+        reader::get_as::<&str>(DOC, "/attributes/0/value"),
+        Ok("Crown")
+    );
+    assert_eq!(
+        reader::get_as::<String>(DOC, "/name"),
+        Ok("Token \"One\"".to_string())
+    );
+    assert_eq!(reader::get_as::<bool>(DOC, "/a~1b/~0"), Ok(true));
+
+    // Escaped strings can't be borrowed from the account data.
+    assert_eq!(
+        reader::get_as::<&str>(DOC, "/name"),
+        Err(OnchainMetadataError::TypeMismatch)
+    );
+    assert_eq!(
+        reader::get_as::<u64>(DOC, "/attributes/2/value"),
+        Err(OnchainMetadataError::TypeMismatch)
+    );
+}
+
+#[test]
+fn test_reader_matches_serde_json() {
+    let doc: serde_json::Value = serde_json::from_slice(DOC).unwrap();
+    for pointer in [
+        "/name",
+        "/level",
+        "/tags",
+        "/a~1b",
+        "/attributes/0",
+        "/attributes/1/value/1",
+        "/attributes/3/value",
+    ] {
+        let value: serde_json::Value =
+            serde_json::from_slice(reader::get(DOC, pointer).unwrap()).unwrap();
+        assert_eq!(Some(&value), doc.pointer(pointer), "{}", pointer);
+    }
+}
+
+#[test]
+fn test_reader_load() {
+    let json_key = Pubkey::new_unique();
+    let (metadata_key, bump) = find_metadata_account(&json_key);
+    let mut json_metadata = JsonMetadata::new(bump, Pubkey::new_unique());
+
+    // Runs `load` against the accounts as the program would have left them, with `owner` owning
+    // both and `metadata_key` holding `json_metadata`.
+    let load = |json_metadata: &JsonMetadata, metadata_key: Pubkey, owner: Pubkey| {
+        let (mut json_lamports, mut metadata_lamports) = (1, 1);
+        let mut json_data = DOC.to_vec();
+        let mut metadata_data = json_metadata.try_to_vec().unwrap();
+        let json_account = AccountInfo::new(
+            &json_key,
+            false,
+            false,
+            &mut json_lamports,
+            &mut json_data,
+            &owner,
+            false,
+            0,
+        );
+        let json_metadata_account = AccountInfo::new(
+            &metadata_key,
+            false,
+            false,
+            &mut metadata_lamports,
+            &mut metadata_data,
+            &owner,
+            false,
+            0,
+        );
+        let data = reader::load(&json_account, &json_metadata_account)?;
+        Ok::<u64, ProgramError>(reader::get_as(&data, "/attributes/3/value")?)
+    };
+
+    assert_eq!(load(&json_metadata, metadata_key, solana_json::ID), Ok(42));
+    json_metadata.encoding = Encoding::CanonicalJson;
+    assert_eq!(load(&json_metadata, metadata_key, solana_json::ID), Ok(42));
+
+    assert_eq!(
+        load(&json_metadata, metadata_key, Pubkey::new_unique()),
+        Err(ProgramError::IllegalOwner)
+    );
+    assert_eq!(
+        load(&json_metadata, Pubkey::new_unique(), solana_json::ID),
+        Err(OnchainMetadataError::MetadataDerivedKeyInvalid.into())
+    );
+
+    json_metadata.encoding = Encoding::MessagePack;
+    assert_eq!(
+        reader::check_encoding(&json_metadata),
+        Err(OnchainMetadataError::UnsupportedEncoding)
+    );
+    assert_eq!(
+        load(&json_metadata, metadata_key, solana_json::ID),
+        Err(OnchainMetadataError::UnsupportedEncoding.into())
+    );
+}
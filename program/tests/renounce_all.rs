@@ -0,0 +1,135 @@
+mod utils;
+
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{
+        self, AddAuthorityArgs, ProposeAuthorityArgs, RemoveAuthorityArgs, SetValueArgs,
+    },
+    pda::find_metadata_account,
+    state::{JsonMetadata, ROLE_ADMIN, ROLE_WRITER},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+use utils::*;
+
+#[tokio::test]
+async fn test_remove_last_authority() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+    let other = Pubkey::new_unique();
+
+    let grant = |key: Pubkey, roles: u8| {
+        instruction::add_authority(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+            AddAuthorityArgs {
+                new_authority: key,
+                roles,
+                paths: vec![],
+            },
+        )
+    };
+    let remove = |key: Pubkey| {
+        instruction::remove_authority(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+            RemoveAuthorityArgs { authority: key },
+        )
+    };
+
+    let result = process(&mut context, &[remove(payer)], &[]).await;
+    assert_error(result, OnchainMetadataError::LastAuthority);
+
+    // Demoting the last admin would orphan the account just the same.
+    let result = process(&mut context, &[grant(payer, ROLE_WRITER)], &[]).await;
+    assert_error(result, OnchainMetadataError::LastAuthority);
+
+    // Other authorities can still be removed.
+    process(
+        &mut context,
+        &[grant(other, ROLE_WRITER), remove(other)],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert_eq!(json_metadata.authorities.len(), 1);
+    assert!(json_metadata.has_role(&payer, ROLE_ADMIN));
+}
+
+#[tokio::test]
+async fn test_renounce_all() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+
+    let propose_authority = instruction::propose_authority(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        ProposeAuthorityArgs {
+            new_authority: Pubkey::new_unique(),
+            roles: ROLE_ADMIN,
+            paths: vec![],
+        },
+    );
+    let renounce_all = instruction::renounce_all(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+    );
+    process(
+        &mut context,
+        &[propose_authority, renounce_all.clone()],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert!(json_metadata.authorities.is_empty());
+    assert!(json_metadata.pending_authorities.is_empty());
+    assert!(!json_metadata.mutable);
+
+    let set_value = instruction::set_value(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetValueArgs {
+            value: r#"{"name": "Token"}"#.to_string(),
+            expected_revision: None,
+        },
+    );
+    let result = process(&mut context, &[set_value], &[]).await;
+    assert_error(result, OnchainMetadataError::Immutable);
+
+    let result = process(&mut context, &[renounce_all], &[]).await;
+    assert_error(result, OnchainMetadataError::Immutable);
+}
@@ -0,0 +1,89 @@
+mod utils;
+
+use solana_json::{
+    instruction::{self, DeleteAtPointerArgs, SetValueArgs},
+    pda::find_metadata_account,
+};
+use solana_program::{pubkey::Pubkey, system_instruction};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::signature::{Keypair, Signer};
+use utils::*;
+
+async fn lamports(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    context.banks_client.get_balance(address).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_rent_destination() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+    let treasury = Keypair::new().pubkey();
+
+    let fund = system_instruction::transfer(&payer, &treasury, 1_000_000_000);
+    let set_value = instruction::set_value(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        treasury,
+        SetValueArgs {
+            value: serde_json::json!({"name": "Token", "notes": "x".repeat(1000)}).to_string(),
+            expected_revision: None,
+        },
+    );
+    process(&mut context, &[fund, set_value], &[])
+        .await
+        .unwrap();
+    let treasury_before = lamports(&mut context, treasury).await;
+    let json_before = lamports(&mut context, json_account).await;
+
+    // Pruning the document hands the rent it no longer needs to the treasury.
+    let delete = instruction::delete_at_pointer(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        treasury,
+        DeleteAtPointerArgs {
+            pointer: "/notes".to_string(),
+            expected_revision: None,
+        },
+    );
+    process(&mut context, &[delete], &[]).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(json_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(account.lamports, rent.minimum_balance(account.data.len()));
+    assert_eq!(
+        lamports(&mut context, treasury).await,
+        treasury_before + json_before - account.lamports
+    );
+
+    // Closing sends everything to the treasury rather than the payer.
+    let treasury_before = lamports(&mut context, treasury).await;
+    let reclaimed = account.lamports + lamports(&mut context, json_metadata_account).await;
+    let close = instruction::close(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        treasury,
+    );
+    process(&mut context, &[close], &[]).await.unwrap();
+
+    assert_eq!(
+        lamports(&mut context, treasury).await,
+        treasury_before + reclaimed
+    );
+    assert_eq!(lamports(&mut context, json_account).await, 0);
+    assert_eq!(lamports(&mut context, json_metadata_account).await, 0);
+}
@@ -0,0 +1,228 @@
+mod utils;
+
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{
+        self, AddAuthorityArgs, AppendValueArgs, DeleteAtPointerArgs, PatchValueArgs,
+        SetAtPointerArgs, SetThresholdArgs, SetValueArgs,
+    },
+    pda::find_metadata_account,
+    state::{JsonMetadata, ROLE_WRITER},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::signature::Signer;
+use utils::*;
+
+async fn metadata(context: &mut ProgramTestContext, address: Pubkey) -> JsonMetadata {
+    context
+        .banks_client
+        .get_account_data_with_borsh(address)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_revision() {
+    let mut context = program_test().start_with_context().await;
+    let json_keypair = initialize(&mut context).await;
+    let json_account = json_keypair.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+
+    let set_value = |value: &str, expected_revision| {
+        instruction::set_value(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+            payer,
+            SetValueArgs {
+                value: value.to_string(),
+                expected_revision,
+            },
+        )
+    };
+
+    let json_metadata = metadata(&mut context, json_metadata_account).await;
+    assert_eq!(json_metadata.revision, 0);
+    assert_eq!(json_metadata.last_updated_slot, 0);
+
+    context.warp_to_slot(100).unwrap();
+    process(
+        &mut context,
+        &[set_value(r#"{"name": "Token"}"#, Some(0))],
+        &[],
+    )
+    .await
+    .unwrap();
+    let json_metadata = metadata(&mut context, json_metadata_account).await;
+    assert_eq!(json_metadata.revision, 1);
+    assert!(json_metadata.last_updated_slot >= 100);
+
+    // A writer that read revision 0 loses the race instead of clobbering the newer data.
+    let result = process(
+        &mut context,
+        &[set_value(r#"{"name": "Stale"}"#, Some(0))],
+        &[],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::RevisionMismatch);
+    let append = |expected_revision| {
+        instruction::append_value(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+            payer,
+            AppendValueArgs {
+                value: r#"{"name": " One"}"#.to_string(),
+                expected_revision,
+            },
+        )
+    };
+    let result = process(&mut context, &[append(Some(0))], &[]).await;
+    assert_error(result, OnchainMetadataError::RevisionMismatch);
+
+    // Every write bumps the revision, whether or not it asked for one.
+    let delete = instruction::delete_at_pointer(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        DeleteAtPointerArgs {
+            pointer: "/level".to_string(),
+            expected_revision: None,
+        },
+    );
+    process(
+        &mut context,
+        &[append(Some(1)), set_value(r#"{"level": 1}"#, None), delete],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        metadata(&mut context, json_metadata_account).await.revision,
+        4
+    );
+    assert_eq!(
+        json_data(&mut context, &json_keypair).await,
+        serde_json::json!({"name": "Token One"})
+    );
+}
+
+#[tokio::test]
+async fn test_revision_covers_every_change() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+
+    // Metadata changes bump the revision like writes to the JSON data.
+    context.warp_to_slot(100).unwrap();
+    process(
+        &mut context,
+        &[
+            instruction::add_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                AddAuthorityArgs {
+                    new_authority: Pubkey::new_unique(),
+                    roles: ROLE_WRITER,
+                    paths: vec![],
+                },
+            ),
+            instruction::set_threshold(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                SetThresholdArgs { threshold: 1 },
+            ),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    let json_metadata = metadata(&mut context, json_metadata_account).await;
+    assert_eq!(json_metadata.revision, 2);
+    assert!(json_metadata.last_updated_slot >= 100);
+
+    // Every write can be made conditional on the revision it was built against.
+    let patch_value = |expected_revision| {
+        instruction::patch_value(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+            payer,
+            PatchValueArgs {
+                patch: r#"[{"op": "replace", "path": "", "value": {"name": "Token"}}]"#.to_string(),
+                expected_revision,
+            },
+        )
+    };
+    let set_at_pointer = |expected_revision| {
+        instruction::set_at_pointer(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+            payer,
+            SetAtPointerArgs {
+                pointer: "/level".to_string(),
+                value: "1".to_string(),
+                expected_revision,
+            },
+        )
+    };
+    let delete_at_pointer = |expected_revision| {
+        instruction::delete_at_pointer(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+            payer,
+            DeleteAtPointerArgs {
+                pointer: "/level".to_string(),
+                expected_revision,
+            },
+        )
+    };
+
+    for ix in [
+        patch_value(Some(1)),
+        set_at_pointer(Some(1)),
+        delete_at_pointer(Some(1)),
+    ] {
+        let result = process(&mut context, &[ix], &[]).await;
+        assert_error(result, OnchainMetadataError::RevisionMismatch);
+    }
+    process(
+        &mut context,
+        &[
+            patch_value(Some(2)),
+            set_at_pointer(Some(3)),
+            delete_at_pointer(Some(4)),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        metadata(&mut context, json_metadata_account).await.revision,
+        5
+    );
+}
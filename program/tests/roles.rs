@@ -0,0 +1,456 @@
+mod utils;
+
+use borsh::BorshSerialize;
+use serde_json::json;
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{self, AddAuthorityArgs, AppendValueArgs, SetAtPointerArgs, SetValueArgs},
+    pda::find_metadata_account,
+    state::{
+        AuthorityEntry, JsonMetadata, LegacyJsonMetadata, ROLE_ADMIN, ROLE_ALL, ROLE_APPENDER,
+        ROLE_WRITER,
+    },
+};
+use solana_program::{hash::hash, instruction::Instruction, pubkey::Pubkey, system_instruction};
+use solana_program_test::tokio;
+use solana_sdk::{
+    account::Account,
+    signature::{Keypair, Signer},
+};
+use utils::*;
+
+fn set_value(json_account: Pubkey, authority: Pubkey, value: &str) -> Instruction {
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    instruction::set_value(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        authority,
+        authority,
+        authority,
+        SetValueArgs {
+            value: value.to_string(),
+            expected_revision: None,
+        },
+    )
+}
+
+fn append_value(json_account: Pubkey, authority: Pubkey, value: &str) -> Instruction {
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    instruction::append_value(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        authority,
+        authority,
+        authority,
+        AppendValueArgs {
+            value: value.to_string(),
+            expected_revision: None,
+        },
+    )
+}
+
+#[tokio::test]
+async fn test_roles() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+    let appender = Keypair::new();
+    let writer = Keypair::new();
+
+    let fund = |to: Pubkey| system_instruction::transfer(&payer, &to, 100_000_000);
+    let grant = |key: Pubkey, roles: u8| {
+        instruction::add_authority(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+            AddAuthorityArgs {
+                new_authority: key,
+                roles,
+                paths: vec![],
+            },
+        )
+    };
+    process(
+        &mut context,
+        &[
+            fund(appender.pubkey()),
+            fund(writer.pubkey()),
+            grant(appender.pubkey(), ROLE_APPENDER),
+            grant(writer.pubkey(), ROLE_APPENDER),
+            // Granting again replaces the roles of an existing authority.
+            grant(writer.pubkey(), ROLE_WRITER),
+            set_value(json_account, payer, r#"{"name": "Token"}"#),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    // Appenders can only append.
+    process(
+        &mut context,
+        &[append_value(
+            json_account,
+            appender.pubkey(),
+            r#"{"name": " #1"}"#,
+        )],
+        &[&appender],
+    )
+    .await
+    .unwrap();
+    let result = process(
+        &mut context,
+        &[set_value(
+            json_account,
+            appender.pubkey(),
+            r#"{"name": "Other"}"#,
+        )],
+        &[&appender],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::InvalidAuthority);
+
+    // Writers can change the data but not manage authorities.
+    process(
+        &mut context,
+        &[
+            set_value(json_account, writer.pubkey(), r#"{"level": 1}"#),
+            append_value(json_account, writer.pubkey(), r#"{"name": "!"}"#),
+        ],
+        &[&writer],
+    )
+    .await
+    .unwrap();
+    let result = process(
+        &mut context,
+        &[instruction::add_authority(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            writer.pubkey(),
+            writer.pubkey(),
+            AddAuthorityArgs {
+                new_authority: writer.pubkey(),
+                roles: ROLE_ALL,
+                paths: vec![],
+            },
+        )],
+        &[&writer],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::InvalidAuthority);
+
+    // Admins can't be added directly, only through a proposal.
+    let result = process(&mut context, &[grant(appender.pubkey(), ROLE_ALL)], &[]).await;
+    assert_error(result, OnchainMetadataError::AdminRequiresProposal);
+    let result = process(
+        &mut context,
+        &[grant(Pubkey::new_unique(), ROLE_ADMIN)],
+        &[],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::AdminRequiresProposal);
+
+    let result = process(&mut context, &[grant(appender.pubkey(), 0)], &[]).await;
+    assert_error(result, OnchainMetadataError::InvalidRoles);
+    let result = process(&mut context, &[grant(appender.pubkey(), 1 << 7)], &[]).await;
+    assert_error(result, OnchainMetadataError::InvalidRoles);
+
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert_eq!(
+        json_metadata.authorities,
+        vec![
+            AuthorityEntry {
+                key: payer,
+                roles: ROLE_ALL,
+                paths: vec![],
+            },
+            AuthorityEntry {
+                key: appender.pubkey(),
+                roles: ROLE_APPENDER,
+                paths: vec![],
+            },
+            AuthorityEntry {
+                key: writer.pubkey(),
+                roles: ROLE_WRITER,
+                paths: vec![],
+            },
+        ]
+    );
+    assert!(!json_metadata.has_role(&writer.pubkey(), ROLE_ADMIN));
+
+    let account = context
+        .banks_client
+        .get_account(json_account)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&account.data).unwrap(),
+        json!({"name": "Token #1!", "level": 1})
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_legacy_metadata() {
+    let authority = Keypair::new();
+    let json_account = Pubkey::new_unique();
+    let (json_metadata_account, bump) = find_metadata_account(&json_account);
+
+    // Accounts as written by the program before authorities carried roles.
+    let legacy_metadata = LegacyJsonMetadata {
+        bump,
+        mutable: true,
+        authorities: vec![authority.pubkey()],
+    };
+    let mut program_test = program_test();
+    program_test.add_account(
+        json_account,
+        Account {
+            lamports: 1_000_000_000,
+            data: b"null".to_vec(),
+            owner: solana_json::ID,
+            ..Account::default()
+        },
+    );
+    program_test.add_account(
+        json_metadata_account,
+        Account {
+            lamports: 1_000_000_000,
+            data: legacy_metadata.try_to_vec().unwrap(),
+            owner: solana_json::ID,
+            ..Account::default()
+        },
+    );
+    program_test.add_account(
+        authority.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            ..Account::default()
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+
+    let result = process(
+        &mut context,
+        &[set_value(
+            json_account,
+            authority.pubkey(),
+            r#"{"name": "Token"}"#,
+        )],
+        &[&authority],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::MetadataNeedsMigration);
+
+    // Anyone can pay for the migration, and migrating twice is a no-op.
+    let migrate = instruction::migrate(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        context.payer.pubkey(),
+    );
+    process(&mut context, &[migrate.clone()], &[])
+        .await
+        .unwrap();
+    process(&mut context, &[migrate], &[]).await.unwrap();
+
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert!(json_metadata.mutable);
+    assert_eq!(
+        json_metadata.authorities,
+        vec![AuthorityEntry {
+            key: authority.pubkey(),
+            roles: ROLE_ALL,
+            paths: vec![],
+        }]
+    );
+
+    process(
+        &mut context,
+        &[set_value(
+            json_account,
+            authority.pubkey(),
+            r#"{"name": "Token"}"#,
+        )],
+        &[&authority],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_migrate_large_integers() {
+    let authority = Keypair::new();
+    let json_account = Pubkey::new_unique();
+    let (json_metadata_account, bump) = find_metadata_account(&json_account);
+
+    // The legacy program stored any u64, including ones a double can't hold exactly.
+    let legacy_metadata = LegacyJsonMetadata {
+        bump,
+        mutable: true,
+        authorities: vec![authority.pubkey()],
+    };
+    let mut program_test = program_test();
+    program_test.add_account(
+        json_account,
+        Account {
+            lamports: 1_000_000_000,
+            data: br#"{"supply":18446744073709551615}"#.to_vec(),
+            owner: solana_json::ID,
+            ..Account::default()
+        },
+    );
+    program_test.add_account(
+        json_metadata_account,
+        Account {
+            lamports: 1_000_000_000,
+            data: legacy_metadata.try_to_vec().unwrap(),
+            owner: solana_json::ID,
+            ..Account::default()
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+
+    let migrate = instruction::migrate(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        context.payer.pubkey(),
+    );
+    process(&mut context, &[migrate], &[]).await.unwrap();
+
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert_eq!(
+        json_metadata.hash,
+        hash(br#"{"supply":18446744073709551615}"#).to_bytes()
+    );
+}
+
+#[tokio::test]
+async fn test_path_permissions() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+    let server = Keypair::new();
+
+    process(
+        &mut context,
+        &[
+            system_instruction::transfer(&payer, &server.pubkey(), 100_000_000),
+            instruction::add_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                AddAuthorityArgs {
+                    new_authority: server.pubkey(),
+                    roles: ROLE_WRITER,
+                    paths: vec!["/stats".to_string(), "/attributes/1".to_string()],
+                },
+            ),
+            set_value(
+                json_account,
+                payer,
+                r#"{"name": "Hero", "stats": {"hp": 10}, "attributes": [1, 2]}"#,
+            ),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let set_at_pointer = |pointer: &str, value: &str| {
+        instruction::set_at_pointer(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            server.pubkey(),
+            server.pubkey(),
+            server.pubkey(),
+            SetAtPointerArgs {
+                pointer: pointer.to_string(),
+                value: value.to_string(),
+                expected_revision: None,
+            },
+        )
+    };
+
+    process(
+        &mut context,
+        &[
+            set_value(
+                json_account,
+                server.pubkey(),
+                r#"{"stats": {"hp": 8, "xp": 3}}"#,
+            ),
+            set_at_pointer("/attributes/1", "5"),
+            // Writing an unchanged value elsewhere doesn't touch that path.
+            set_value(json_account, server.pubkey(), r#"{"name": "Hero"}"#),
+        ],
+        &[&server],
+    )
+    .await
+    .unwrap();
+
+    for ix in [
+        set_value(json_account, server.pubkey(), r#"{"name": "Villain"}"#),
+        set_value(json_account, server.pubkey(), r#"{"statsX": 1}"#),
+        set_at_pointer("/attributes/0", "5"),
+        // Growing the array changes the array itself, not only the permitted element.
+        set_at_pointer("/attributes/-", "5"),
+        set_at_pointer("", "{}"),
+    ] {
+        let result = process(&mut context, &[ix], &[&server]).await;
+        assert_error(result, OnchainMetadataError::PathNotPermitted);
+    }
+
+    let result = process(
+        &mut context,
+        &[instruction::add_authority(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            payer,
+            AddAuthorityArgs {
+                new_authority: server.pubkey(),
+                roles: ROLE_WRITER,
+                paths: vec!["stats".to_string()],
+            },
+        )],
+        &[],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::InvalidPointer);
+
+    let account = context
+        .banks_client
+        .get_account(json_account)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&account.data).unwrap(),
+        json!({"name": "Hero", "stats": {"hp": 8, "xp": 3}, "attributes": [1, 5]})
+    );
+}
@@ -0,0 +1,142 @@
+mod utils;
+
+use serde_json::json;
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{self, SetSchemaArgs, SetValueArgs},
+    pda::{find_metadata_account, find_schema_account},
+    schema,
+};
+use solana_program::instruction::AccountMeta;
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+use utils::*;
+
+#[test]
+fn test_schema_validation() {
+    let nft_schema = json!({
+        "type": "object",
+        "required": ["name", "image"],
+        "properties": {
+            "name": {"type": "string", "maxLength": 8},
+            "image": {"type": "string"},
+            "attributes": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["trait_type", "value"],
+                    "properties": {"trait_type": {"enum": ["Color", "Size"]}},
+                },
+            },
+        },
+    });
+    schema::check(&nft_schema).unwrap();
+
+    let valid = json!({
+        "name": "Token",
+        "image": "https://example.com/1.png",
+        "attributes": [{"trait_type": "Color", "value": "Red"}],
+    });
+    schema::validate(&nft_schema, &valid).unwrap();
+
+    let invalid = [
+        json!({"name": "Token"}),
+        json!({"name": "Too long a name", "image": ""}),
+        json!({"name": 1, "image": ""}),
+        json!({"name": "Token", "image": "", "attributes": [{"trait_type": "Shape", "value": 1}]}),
+        json!(null),
+    ];
+    for doc in invalid {
+        assert_eq!(
+            schema::validate(&nft_schema, &doc),
+            Err(OnchainMetadataError::SchemaViolation),
+            "{}",
+            doc
+        );
+    }
+
+    assert_eq!(
+        schema::check(&json!({"type": "date"})),
+        Err(OnchainMetadataError::InvalidSchema)
+    );
+    assert_eq!(
+        schema::check(&json!({"properties": {"name": {"maxLength": -1}}})),
+        Err(OnchainMetadataError::InvalidSchema)
+    );
+}
+
+#[tokio::test]
+async fn test_schema_enforced_on_write() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let (schema_account, _) = find_schema_account(&json_account.pubkey());
+    let payer = context.payer.pubkey();
+
+    let set_schema = instruction::set_schema(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        schema_account,
+        payer,
+        payer,
+        SetSchemaArgs {
+            schema: r#"{"type": "object", "properties": {"name": {"type": "string"}}}"#.to_string(),
+        },
+    );
+    process(&mut context, &[set_schema], &[]).await.unwrap();
+
+    let set_value = |value: &str, with_schema: bool| {
+        let mut ix = instruction::set_value(
+            solana_json::ID,
+            json_account.pubkey(),
+            json_metadata_account,
+            payer,
+            payer,
+            payer,
+            SetValueArgs {
+                value: value.to_string(),
+                expected_revision: None,
+            },
+        );
+        if with_schema {
+            ix.accounts
+                .push(AccountMeta::new_readonly(schema_account, false));
+        }
+        ix
+    };
+
+    process(
+        &mut context,
+        &[set_value(r#"{"name": "Token"}"#, true)],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let result = process(&mut context, &[set_value(r#"{"name": 1}"#, true)], &[]).await;
+    assert_error(result, OnchainMetadataError::SchemaViolation);
+
+    // The schema account can't be left out once a schema is attached.
+    let result = process(&mut context, &[set_value(r#"{"name": 1}"#, false)], &[]).await;
+    assert!(result.is_err());
+
+    let remove_schema = instruction::remove_schema(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        schema_account,
+        payer,
+        payer,
+        payer,
+    );
+    process(&mut context, &[remove_schema], &[]).await.unwrap();
+
+    process(&mut context, &[set_value(r#"{"name": 1}"#, false)], &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        json_data(&mut context, &json_account).await,
+        json!({"name": 1})
+    );
+}
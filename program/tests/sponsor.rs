@@ -0,0 +1,135 @@
+mod utils;
+
+use serde_json::json;
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{self, AddAuthorityArgs, SetValueArgs},
+    pda::find_metadata_account,
+    state::{AuthorityEntry, JsonMetadata, ROLE_ALL, ROLE_WRITER},
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use utils::*;
+
+#[tokio::test]
+async fn test_sponsored_write() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let payer = context.payer.pubkey();
+    // The user holds no lamports at all, so every write must be paid for by the sponsor.
+    let user = Keypair::new();
+
+    process(
+        &mut context,
+        &[instruction::add_authority(
+            solana_json::ID,
+            json_account.pubkey(),
+            json_metadata_account,
+            payer,
+            payer,
+            AddAuthorityArgs {
+                new_authority: user.pubkey(),
+                roles: ROLE_WRITER,
+                paths: vec![],
+            },
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let set_value = |authority, value: &str| {
+        instruction::set_value(
+            solana_json::ID,
+            json_account.pubkey(),
+            json_metadata_account,
+            authority,
+            payer,
+            payer,
+            SetValueArgs {
+                value: value.to_string(),
+                expected_revision: None,
+            },
+        )
+    };
+
+    process(
+        &mut context,
+        &[set_value(user.pubkey(), r#"{"name": "Sponsored"}"#)],
+        &[&user],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        json_data(&mut context, &json_account).await,
+        json!({"name": "Sponsored"})
+    );
+    assert!(context
+        .banks_client
+        .get_account(user.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+
+    // Signing as the authority is still required.
+    let stranger = Keypair::new();
+    let result = process(
+        &mut context,
+        &[set_value(stranger.pubkey(), r#"{"name": "Other"}"#)],
+        &[&stranger],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::InvalidAuthority);
+}
+
+#[tokio::test]
+async fn test_sponsored_initialize() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = Keypair::new();
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    // The sponsor pays to create an account that only the user controls.
+    let user = Keypair::new();
+
+    let initialize = instruction::initialize(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        user.pubkey(),
+        context.payer.pubkey(),
+    );
+    process(&mut context, &[initialize], &[&json_account])
+        .await
+        .unwrap();
+
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert_eq!(
+        json_metadata.authorities,
+        vec![AuthorityEntry {
+            key: user.pubkey(),
+            roles: ROLE_ALL,
+            paths: vec![],
+        }]
+    );
+
+    // The sponsor itself isn't an authority.
+    let payer = context.payer.pubkey();
+    let set_value = instruction::set_value(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetValueArgs {
+            value: r#"{"name": "Other"}"#.to_string(),
+            expected_revision: None,
+        },
+    );
+    let result = process(&mut context, &[set_value], &[]).await;
+    assert_error(result, OnchainMetadataError::InvalidAuthority);
+}
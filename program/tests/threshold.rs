@@ -0,0 +1,353 @@
+mod utils;
+
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{
+        self, AddAuthorityArgs, ProposeAuthorityArgs, RemoveAuthorityArgs, SetThresholdArgs,
+        SetValueArgs,
+    },
+    pda::find_metadata_account,
+    state::{JsonMetadata, ROLE_ADMIN, ROLE_ALL, ROLE_WRITER},
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use utils::*;
+
+fn cosigned(mut ix: Instruction, cosigners: &[Pubkey]) -> Instruction {
+    ix.accounts.extend(
+        cosigners
+            .iter()
+            .map(|cosigner| AccountMeta::new_readonly(*cosigner, true)),
+    );
+    ix
+}
+
+#[tokio::test]
+async fn test_threshold() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+    let admin = Keypair::new();
+    let outsider = Keypair::new();
+
+    let set_threshold = |threshold| {
+        instruction::set_threshold(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            SetThresholdArgs { threshold },
+        )
+    };
+    let set_value = instruction::set_value(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetValueArgs {
+            value: r#"{"name": "Token"}"#.to_string(),
+            expected_revision: None,
+        },
+    );
+
+    process(
+        &mut context,
+        &[
+            instruction::propose_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                ProposeAuthorityArgs {
+                    new_authority: admin.pubkey(),
+                    roles: ROLE_ALL,
+                    paths: vec![],
+                },
+            ),
+            instruction::accept_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                admin.pubkey(),
+                payer,
+            ),
+            set_threshold(2),
+        ],
+        &[&admin],
+    )
+    .await
+    .unwrap();
+
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert_eq!(json_metadata.threshold, 2);
+
+    let result = process(&mut context, &[set_value.clone()], &[]).await;
+    assert_error(result, OnchainMetadataError::NotEnoughSigners);
+
+    // Signers that aren't distinct authorities don't count towards the threshold.
+    let result = process(&mut context, &[cosigned(set_value.clone(), &[payer])], &[]).await;
+    assert_error(result, OnchainMetadataError::NotEnoughSigners);
+    let result = process(
+        &mut context,
+        &[cosigned(set_value.clone(), &[outsider.pubkey()])],
+        &[&outsider],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::NotEnoughSigners);
+
+    process(
+        &mut context,
+        &[cosigned(set_value, &[admin.pubkey()])],
+        &[&admin],
+    )
+    .await
+    .unwrap();
+
+    // The threshold can't exceed the number of admins, nor can removals push it out of reach.
+    let result = process(
+        &mut context,
+        &[cosigned(set_threshold(3), &[admin.pubkey()])],
+        &[&admin],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::InvalidThreshold);
+    let result = process(
+        &mut context,
+        &[cosigned(set_threshold(0), &[admin.pubkey()])],
+        &[&admin],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::InvalidThreshold);
+    let result = process(
+        &mut context,
+        &[cosigned(
+            instruction::remove_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                RemoveAuthorityArgs {
+                    authority: admin.pubkey(),
+                },
+            ),
+            &[admin.pubkey()],
+        )],
+        &[&admin],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::InvalidThreshold);
+
+    // Lowering the threshold needs the current threshold too.
+    let result = process(&mut context, &[set_threshold(1)], &[]).await;
+    assert_error(result, OnchainMetadataError::NotEnoughSigners);
+    process(
+        &mut context,
+        &[cosigned(set_threshold(1), &[admin.pubkey()])],
+        &[&admin],
+    )
+    .await
+    .unwrap();
+
+    let json_metadata: JsonMetadata = context
+        .banks_client
+        .get_account_data_with_borsh(json_metadata_account)
+        .await
+        .unwrap();
+    assert_eq!(json_metadata.threshold, 1);
+    assert_eq!(json_metadata.admin_count(), 2);
+    assert!(json_metadata.has_role(&admin.pubkey(), ROLE_ADMIN));
+}
+
+#[tokio::test]
+async fn test_threshold_ignores_restricted_cosigners() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+    let admin = Keypair::new();
+    let writer = Keypair::new();
+
+    process(
+        &mut context,
+        &[
+            instruction::propose_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                ProposeAuthorityArgs {
+                    new_authority: admin.pubkey(),
+                    roles: ROLE_ALL,
+                    paths: vec![],
+                },
+            ),
+            instruction::accept_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                admin.pubkey(),
+                payer,
+            ),
+            instruction::add_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                AddAuthorityArgs {
+                    new_authority: writer.pubkey(),
+                    roles: ROLE_WRITER,
+                    paths: vec!["/name".to_string()],
+                },
+            ),
+            instruction::set_threshold(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                SetThresholdArgs { threshold: 2 },
+            ),
+        ],
+        &[&admin],
+    )
+    .await
+    .unwrap();
+
+    // A writer limited to `/name` can't approve a change to the rest of the document.
+    let set_value = instruction::set_value(
+        solana_json::ID,
+        json_account,
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        SetValueArgs {
+            value: r#"{"name": "Token", "level": 1}"#.to_string(),
+            expected_revision: None,
+        },
+    );
+    let result = process(
+        &mut context,
+        &[cosigned(set_value.clone(), &[writer.pubkey()])],
+        &[&writer],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::NotEnoughSigners);
+
+    process(
+        &mut context,
+        &[cosigned(set_value, &[admin.pubkey()])],
+        &[&admin],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_threshold_reachable_by_writers() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let payer = context.payer.pubkey();
+    let admin = Keypair::new();
+    let writer = Keypair::new();
+
+    let set_threshold = |threshold| {
+        instruction::set_threshold(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+            SetThresholdArgs { threshold },
+        )
+    };
+
+    // Two admins, but only the payer can write, so writes could never gather two signers.
+    process(
+        &mut context,
+        &[
+            instruction::propose_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                ProposeAuthorityArgs {
+                    new_authority: admin.pubkey(),
+                    roles: ROLE_ADMIN,
+                    paths: vec![],
+                },
+            ),
+            instruction::accept_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                admin.pubkey(),
+                payer,
+            ),
+        ],
+        &[&admin],
+    )
+    .await
+    .unwrap();
+    let result = process(&mut context, &[set_threshold(2)], &[]).await;
+    assert_error(result, OnchainMetadataError::InvalidThreshold);
+
+    // A second writer makes the threshold reachable.
+    process(
+        &mut context,
+        &[
+            instruction::add_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                AddAuthorityArgs {
+                    new_authority: writer.pubkey(),
+                    roles: ROLE_WRITER,
+                    paths: vec![],
+                },
+            ),
+            set_threshold(2),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    // Removing that writer would put writes out of reach again.
+    let result = process(
+        &mut context,
+        &[cosigned(
+            instruction::remove_authority(
+                solana_json::ID,
+                json_account,
+                json_metadata_account,
+                payer,
+                payer,
+                RemoveAuthorityArgs {
+                    authority: writer.pubkey(),
+                },
+            ),
+            &[admin.pubkey()],
+        )],
+        &[&admin],
+    )
+    .await;
+    assert_error(result, OnchainMetadataError::InvalidThreshold);
+}
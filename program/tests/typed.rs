@@ -0,0 +1,109 @@
+mod utils;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use solana_json::{
+    pda::find_metadata_account,
+    state::Encoding,
+    typed::{append_typed, read_typed, set_typed, TypedError},
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+use utils::*;
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+struct Profile {
+    name: String,
+    level: u8,
+}
+
+#[derive(Debug, Serialize)]
+struct Suffix {
+    name: &'static str,
+}
+
+#[tokio::test]
+async fn test_typed_round_trip() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let payer = context.payer.pubkey();
+
+    let profile = Profile {
+        name: "Player".to_string(),
+        level: 3,
+    };
+    let set = set_typed(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        &profile,
+    )
+    .unwrap();
+    let append = append_typed(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+        payer,
+        &Suffix { name: " One" },
+    )
+    .unwrap();
+    process(&mut context, &[set, append], &[]).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(json_account.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        read_typed::<Profile>(&account.data, Encoding::Json).unwrap(),
+        Profile {
+            name: "Player One".to_string(),
+            level: 3,
+        }
+    );
+}
+
+#[test]
+fn test_read_typed_message_pack() {
+    let data = Encoding::MessagePack
+        .serialize(&json!({"name": "Player", "level": 3}))
+        .unwrap();
+    assert_eq!(
+        read_typed::<Profile>(&data, Encoding::MessagePack).unwrap(),
+        Profile {
+            name: "Player".to_string(),
+            level: 3,
+        }
+    );
+    assert!(matches!(
+        read_typed::<Profile>(&data, Encoding::Json),
+        Err(TypedError::NotDecodable(Encoding::Json))
+    ));
+}
+
+#[test]
+fn test_read_typed_errors() {
+    assert!(matches!(
+        read_typed::<Profile>(b"{\"name\": ", Encoding::Json),
+        Err(TypedError::NotDecodable(Encoding::Json))
+    ));
+    assert!(matches!(
+        read_typed::<Profile>(b"\0\0\0\0", Encoding::CanonicalJson),
+        Err(TypedError::NotDecodable(Encoding::CanonicalJson))
+    ));
+    assert!(matches!(
+        read_typed::<Profile>(br#"{"name": "Player", "level": "high"}"#, Encoding::Json),
+        Err(TypedError::Mismatch(_))
+    ));
+    assert!(matches!(
+        read_typed::<Profile>(b"null", Encoding::Json),
+        Err(TypedError::Mismatch(_))
+    ));
+}
@@ -0,0 +1,356 @@
+mod utils;
+
+use serde_json::json;
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{self, AddAuthorityArgs, BeginUploadArgs, CommitUploadArgs, WriteChunkArgs},
+    pda::{find_buffer_account, find_metadata_account},
+    state::ROLE_WRITER,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use utils::*;
+
+#[tokio::test]
+async fn test_chunked_upload() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let (buffer_account, _) = find_buffer_account(&json_account.pubkey());
+    let payer = context.payer.pubkey();
+
+    let attributes: Vec<_> = (0..64)
+        .map(|i| json!({"trait_type": format!("Trait {}", i), "value": i}))
+        .collect();
+    let document = json!({"name": "Large", "attributes": attributes});
+    let bytes = serde_json::to_vec(&document).unwrap();
+    assert!(bytes.len() > 2000);
+
+    let begin = instruction::begin_upload(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        payer,
+        BeginUploadArgs {
+            size: bytes.len() as u32,
+        },
+    );
+    process(&mut context, &[begin], &[]).await.unwrap();
+
+    for (i, chunk) in bytes.chunks(800).enumerate() {
+        let write = instruction::write_chunk(
+            solana_json::ID,
+            json_account.pubkey(),
+            json_metadata_account,
+            buffer_account,
+            payer,
+            WriteChunkArgs {
+                offset: (i * 800) as u32,
+                bytes: chunk.to_vec(),
+            },
+        );
+        process(&mut context, &[write], &[]).await.unwrap();
+    }
+
+    let overflow = instruction::write_chunk(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        WriteChunkArgs {
+            offset: bytes.len() as u32 - 1,
+            bytes: vec![b' ', b' '],
+        },
+    );
+    let result = process(&mut context, &[overflow], &[]).await;
+    assert_error(result, OnchainMetadataError::ChunkOutOfBounds);
+
+    let commit = instruction::commit_upload(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        payer,
+        payer,
+        CommitUploadArgs {
+            merge: false,
+            expected_revision: None,
+        },
+    );
+    process(&mut context, &[commit], &[]).await.unwrap();
+
+    assert_eq!(json_data(&mut context, &json_account).await, document);
+    assert!(context
+        .banks_client
+        .get_account(buffer_account)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_commit_rejects_incomplete_upload() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let (buffer_account, _) = find_buffer_account(&json_account.pubkey());
+    let payer = context.payer.pubkey();
+
+    let begin = instruction::begin_upload(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        payer,
+        BeginUploadArgs { size: 16 },
+    );
+    let write = instruction::write_chunk(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        WriteChunkArgs {
+            offset: 0,
+            bytes: br#"{"name":"#.to_vec(),
+        },
+    );
+    process(&mut context, &[begin, write], &[]).await.unwrap();
+
+    let commit = instruction::commit_upload(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        payer,
+        payer,
+        CommitUploadArgs {
+            merge: true,
+            expected_revision: None,
+        },
+    );
+    let result = process(&mut context, &[commit], &[]).await;
+    assert_error(result, OnchainMetadataError::InvalidJson);
+
+    let cancel = instruction::cancel_upload(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        payer,
+    );
+    process(&mut context, &[cancel], &[]).await.unwrap();
+    assert!(context
+        .banks_client
+        .get_account(buffer_account)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_cancel_upload_after_freeze() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let (buffer_account, _) = find_buffer_account(&json_account.pubkey());
+    let payer = context.payer.pubkey();
+
+    let begin = instruction::begin_upload(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        payer,
+        BeginUploadArgs { size: 16 },
+    );
+    let freeze = instruction::freeze(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+    );
+    process(&mut context, &[begin, freeze], &[]).await.unwrap();
+
+    // The frozen account can't take the upload, but its buffer can still be reclaimed.
+    let write = instruction::write_chunk(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        WriteChunkArgs {
+            offset: 0,
+            bytes: b"{}".to_vec(),
+        },
+    );
+    let result = process(&mut context, &[write], &[]).await;
+    assert_error(result, OnchainMetadataError::Immutable);
+
+    let cancel = instruction::cancel_upload(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        payer,
+    );
+    process(&mut context, &[cancel], &[]).await.unwrap();
+    assert!(context
+        .banks_client
+        .get_account(buffer_account)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_close_discards_pending_upload() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let (buffer_account, _) = find_buffer_account(&json_account.pubkey());
+    let payer = context.payer.pubkey();
+
+    let begin = instruction::begin_upload(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        payer,
+        BeginUploadArgs { size: 16 },
+    );
+    process(&mut context, &[begin], &[]).await.unwrap();
+
+    let close = instruction::close(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+    );
+    process(&mut context, &[close], &[]).await.unwrap();
+
+    for address in [json_account.pubkey(), json_metadata_account, buffer_account] {
+        assert!(context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
+
+#[tokio::test]
+async fn test_upload_is_bound_to_uploader() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await;
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let (buffer_account, _) = find_buffer_account(&json_account.pubkey());
+    let payer = context.payer.pubkey();
+    let writer = Keypair::new();
+
+    let add_writer = instruction::add_authority(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        payer,
+        payer,
+        AddAuthorityArgs {
+            new_authority: writer.pubkey(),
+            roles: ROLE_WRITER,
+            paths: vec![],
+        },
+    );
+    let begin = instruction::begin_upload(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        payer,
+        BeginUploadArgs { size: 2 },
+    );
+    process(&mut context, &[add_writer, begin], &[])
+        .await
+        .unwrap();
+
+    // Another writer can't write to, commit or cancel the payer's upload.
+    let write = instruction::write_chunk(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        writer.pubkey(),
+        WriteChunkArgs {
+            offset: 0,
+            bytes: b"{}".to_vec(),
+        },
+    );
+    let result = process(&mut context, &[write], &[&writer]).await;
+    assert_error(result, OnchainMetadataError::InvalidUploader);
+
+    let commit = instruction::commit_upload(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        writer.pubkey(),
+        payer,
+        payer,
+        CommitUploadArgs {
+            merge: false,
+            expected_revision: None,
+        },
+    );
+    let result = process(&mut context, &[commit], &[&writer]).await;
+    assert_error(result, OnchainMetadataError::InvalidUploader);
+
+    let cancel = instruction::cancel_upload(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        writer.pubkey(),
+        payer,
+    );
+    let result = process(&mut context, &[cancel], &[&writer]).await;
+    assert_error(result, OnchainMetadataError::InvalidUploader);
+
+    // The uploader still can.
+    let write = instruction::write_chunk(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        WriteChunkArgs {
+            offset: 0,
+            bytes: b"{}".to_vec(),
+        },
+    );
+    let commit = instruction::commit_upload(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        buffer_account,
+        payer,
+        payer,
+        payer,
+        CommitUploadArgs {
+            merge: false,
+            expected_revision: None,
+        },
+    );
+    process(&mut context, &[write, commit], &[]).await.unwrap();
+    assert_eq!(json_data(&mut context, &json_account).await, json!({}));
+}
@@ -0,0 +1,78 @@
+#![allow(dead_code)]
+
+use solana_json::{error::OnchainMetadataError, instruction, pda::find_metadata_account};
+use solana_program::instruction::{Instruction, InstructionError};
+use solana_program_test::{
+    processor, BanksClientError, ProgramTest, ProgramTestBanksClientExt, ProgramTestContext,
+};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+pub fn program_test() -> ProgramTest {
+    ProgramTest::new(
+        "solana_json",
+        solana_json::ID,
+        processor!(solana_json::processor::Processor::process_instruction),
+    )
+}
+
+pub async fn process(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    // Always use a fresh blockhash so identical transactions aren't rejected as duplicates.
+    let blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await?;
+    context.last_blockhash = blockhash;
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    context.banks_client.process_transaction(transaction).await
+}
+
+/// Creates a new JSON account owned by the context payer and returns its address.
+pub async fn initialize(context: &mut ProgramTestContext) -> Keypair {
+    let json_account = Keypair::new();
+    let (json_metadata_account, _) = find_metadata_account(&json_account.pubkey());
+    let ix = instruction::initialize(
+        solana_json::ID,
+        json_account.pubkey(),
+        json_metadata_account,
+        context.payer.pubkey(),
+        context.payer.pubkey(),
+    );
+    process(context, &[ix], &[&json_account]).await.unwrap();
+    json_account
+}
+
+pub async fn json_data(
+    context: &mut ProgramTestContext,
+    json_account: &Keypair,
+) -> serde_json::Value {
+    let account = context
+        .banks_client
+        .get_account(json_account.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    serde_json::from_slice(&account.data).unwrap()
+}
+
+pub fn assert_error(result: Result<(), BanksClientError>, error: OnchainMetadataError) {
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(code, error as u32)
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
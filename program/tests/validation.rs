@@ -0,0 +1,245 @@
+mod utils;
+
+use solana_json::{
+    error::OnchainMetadataError,
+    instruction::{
+        self, AddAuthorityArgs, AppendValueArgs, BeginUploadArgs, DeleteAtPointerArgs,
+        PatchValueArgs, ProposeAuthorityArgs, RemoveAuthorityArgs, SetAtPointerArgs,
+        SetEncodingArgs, SetSchemaArgs, SetThresholdArgs, SetValueArgs,
+    },
+    pda::{find_buffer_account, find_metadata_account, find_schema_account},
+    state::{Encoding, ROLE_ALL, ROLE_WRITER},
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use utils::*;
+
+/// Builds every instruction that modifies a JSON account, signed by `authority`.
+fn writes(
+    json_account: Pubkey,
+    json_metadata_account: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+) -> Vec<Instruction> {
+    let (buffer_account, _) = find_buffer_account(&json_account);
+    let (schema_account, _) = find_schema_account(&json_account);
+    vec![
+        instruction::set_value(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            authority,
+            payer,
+            payer,
+            SetValueArgs {
+                value: r#"{"name": "Token"}"#.to_string(),
+                expected_revision: None,
+            },
+        ),
+        instruction::append_value(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            authority,
+            payer,
+            payer,
+            AppendValueArgs {
+                value: r#"{"name": " #1"}"#.to_string(),
+                expected_revision: None,
+            },
+        ),
+        instruction::patch_value(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            authority,
+            payer,
+            payer,
+            PatchValueArgs {
+                patch: "[]".to_string(),
+                expected_revision: None,
+            },
+        ),
+        instruction::set_at_pointer(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            authority,
+            payer,
+            payer,
+            SetAtPointerArgs {
+                pointer: "/name".to_string(),
+                value: r#""Token""#.to_string(),
+                expected_revision: None,
+            },
+        ),
+        instruction::delete_at_pointer(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            authority,
+            payer,
+            payer,
+            DeleteAtPointerArgs {
+                pointer: "/name".to_string(),
+                expected_revision: None,
+            },
+        ),
+        instruction::add_authority(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            authority,
+            payer,
+            AddAuthorityArgs {
+                new_authority: Pubkey::new_unique(),
+                roles: ROLE_WRITER,
+                paths: vec![],
+            },
+        ),
+        instruction::remove_authority(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            authority,
+            payer,
+            RemoveAuthorityArgs { authority: payer },
+        ),
+        instruction::propose_authority(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            authority,
+            payer,
+            ProposeAuthorityArgs {
+                new_authority: Pubkey::new_unique(),
+                roles: ROLE_ALL,
+                paths: vec![],
+            },
+        ),
+        instruction::set_threshold(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            authority,
+            SetThresholdArgs { threshold: 1 },
+        ),
+        instruction::begin_upload(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            buffer_account,
+            authority,
+            payer,
+            BeginUploadArgs { size: 16 },
+        ),
+        instruction::set_schema(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            schema_account,
+            authority,
+            payer,
+            SetSchemaArgs {
+                schema: r#"{"type": "object"}"#.to_string(),
+            },
+        ),
+        instruction::set_encoding(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            authority,
+            payer,
+            payer,
+            SetEncodingArgs {
+                encoding: Encoding::CanonicalJson,
+            },
+        ),
+        instruction::freeze(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            authority,
+        ),
+        instruction::renounce_all(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            authority,
+            payer,
+        ),
+        instruction::close(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            authority,
+            payer,
+        ),
+    ]
+}
+
+#[tokio::test]
+async fn test_writes_share_account_checks() {
+    let mut context = program_test().start_with_context().await;
+    let json_account = initialize(&mut context).await.pubkey();
+    let (json_metadata_account, _) = find_metadata_account(&json_account);
+    let other_account = initialize(&mut context).await.pubkey();
+    let (other_metadata_account, _) = find_metadata_account(&other_account);
+    let payer = context.payer.pubkey();
+    let outsider = Keypair::new();
+
+    // Metadata belonging to another JSON account is rejected.
+    for ix in writes(json_account, other_metadata_account, payer, payer) {
+        let result = process(&mut context, &[ix], &[]).await;
+        assert_error(result, OnchainMetadataError::MetadataDerivedKeyInvalid);
+    }
+
+    // So is a JSON account that doesn't exist.
+    let missing_account = Pubkey::new_unique();
+    let (missing_metadata_account, _) = find_metadata_account(&missing_account);
+    for ix in writes(missing_account, missing_metadata_account, payer, payer) {
+        let result = process(&mut context, &[ix], &[]).await;
+        assert_error(result, OnchainMetadataError::NotInitialized);
+    }
+
+    // Signers that aren't authorities can't change anything.
+    for ix in writes(
+        json_account,
+        json_metadata_account,
+        outsider.pubkey(),
+        payer,
+    ) {
+        let result = process(&mut context, &[ix], &[&outsider]).await;
+        assert_error(result, OnchainMetadataError::InvalidAuthority);
+    }
+
+    // Nor can anyone once the account is frozen.
+    process(
+        &mut context,
+        &[instruction::freeze(
+            solana_json::ID,
+            json_account,
+            json_metadata_account,
+            payer,
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    let mut writes = writes(json_account, json_metadata_account, payer, payer);
+    let close = writes.pop().unwrap();
+    for ix in writes {
+        let result = process(&mut context, &[ix], &[]).await;
+        assert_error(result, OnchainMetadataError::Immutable);
+    }
+
+    // Its admins can still close it to recover the rent.
+    process(&mut context, &[close], &[]).await.unwrap();
+    assert!(context
+        .banks_client
+        .get_account(json_account)
+        .await
+        .unwrap()
+        .is_none());
+}